@@ -0,0 +1,583 @@
+use crate::converter::convert;
+use crate::greek::{self, Letter, ACUTE, CIRCUMFLEX, GRAVE};
+use crate::tokenizer::letter_units;
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use std::fmt;
+
+const PUNCTUATION: [char; 8] = ['.', ',', ';', ':', '·', '\'', '’', '"'];
+
+lazy_static! {
+    static ref RE_WORD: Regex = Regex::new(r"\S+").unwrap();
+}
+lazy_static! {
+    static ref RE_BETA_CAPITAL: Regex = Regex::new(r"^\*([()/\\=+|]*)([a-zA-Z])").unwrap();
+}
+
+/// Natural quantity of a vowel or diphthong.
+///
+/// `α`, `ι` and `υ` are [Quantity::Undetermined] unless marked by a macron (`%26`),
+/// a breve (`%27`), a circumflex or an iota subscript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantity {
+    Long,
+    Short,
+    Undetermined,
+}
+
+/// Provides different classes of accentuation errors.
+/// - [AccentuationError::GraveNotOnUltima]: a grave accent anywhere but on the last syllable.
+/// - [AccentuationError::AcuteBeyondAntepenult]: an acute four or more syllables from the end.
+/// - [AccentuationError::CircumflexBeyondPenult]: a circumflex three or more syllables from the end.
+/// - [AccentuationError::CircumflexOnShortVowel]: a circumflex on `ε`, `ο` or a vowel marked short.
+/// - [AccentuationError::LawOfLimitation]: an accent on the antepenult, or a circumflex on
+///   the penult, with a long ultima.
+/// - [AccentuationError::Sotera]: an acute on a long penult followed by a short ultima.
+/// - [AccentuationError::Undetermined]: the rules can not be checked without knowing the
+///   length of an unmarked `α`, `ι` or `υ`.
+#[derive(Debug)]
+pub enum AccentuationError {
+    GraveNotOnUltima(Vec<String>),
+    AcuteBeyondAntepenult(Vec<String>),
+    CircumflexBeyondPenult(Vec<String>),
+    CircumflexOnShortVowel(Vec<String>),
+    LawOfLimitation(Vec<String>),
+    Sotera(Vec<String>),
+    Undetermined(Vec<String>),
+}
+
+impl fmt::Display for AccentuationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccentuationError::GraveNotOnUltima(a) => {
+                write!(f, "Grave accent before the ultima: {:?}", a)
+            }
+            AccentuationError::AcuteBeyondAntepenult(a) => {
+                write!(f, "Acute accent before the antepenult: {:?}", a)
+            }
+            AccentuationError::CircumflexBeyondPenult(a) => {
+                write!(f, "Circumflex accent before the penult: {:?}", a)
+            }
+            AccentuationError::CircumflexOnShortVowel(a) => {
+                write!(f, "Circumflex accent on a short vowel: {:?}", a)
+            }
+            AccentuationError::LawOfLimitation(a) => {
+                write!(f, "Law of limitation violated (long ultima): {:?}", a)
+            }
+            AccentuationError::Sotera(a) => {
+                write!(f, "Sotera rule violated (acute on long penult): {:?}", a)
+            }
+            AccentuationError::Undetermined(a) => {
+                write!(f, "Accent undetermined (unmarked vowel length): {:?}", a)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Rule {
+    Grave,
+    Acute,
+    Circumflex,
+    ShortVowel,
+    Limitation,
+    Sotera,
+}
+
+impl Rule {
+    fn error(self, words: Vec<String>) -> AccentuationError {
+        match self {
+            Rule::Grave => AccentuationError::GraveNotOnUltima(words),
+            Rule::Acute => AccentuationError::AcuteBeyondAntepenult(words),
+            Rule::Circumflex => AccentuationError::CircumflexBeyondPenult(words),
+            Rule::ShortVowel => AccentuationError::CircumflexOnShortVowel(words),
+            Rule::Limitation => AccentuationError::LawOfLimitation(words),
+            Rule::Sotera => AccentuationError::Sotera(words),
+        }
+    }
+}
+
+/// Outcome of checking a single word.
+enum Verdict {
+    Valid,
+    Invalid(Rule),
+    Undetermined,
+}
+
+fn check_word(word: &[Letter]) -> Verdict {
+    let nuclei = greek::nuclei(word);
+    let accented = nuclei
+        .iter()
+        .position(|n| word[n.clone()].iter().any(|l| l.accent().is_some()));
+    let position = match accented {
+        Some(p) => p,
+        None => return Verdict::Valid,
+    };
+    let accent = word[nuclei[position].clone()]
+        .iter()
+        .find_map(|l| l.accent())
+        .unwrap();
+    let from_end = nuclei.len() - 1 - position;
+    let ultima = greek::quantity(word, &nuclei[nuclei.len() - 1], true);
+
+    match (accent, from_end) {
+        (GRAVE, 0) => Verdict::Valid,
+        (GRAVE, _) => Verdict::Invalid(Rule::Grave),
+        (ACUTE, 3..) => Verdict::Invalid(Rule::Acute),
+        (CIRCUMFLEX, 2..) => Verdict::Invalid(Rule::Circumflex),
+        (CIRCUMFLEX, _) if greek::quantity(word, &nuclei[position], false) == Quantity::Short => {
+            Verdict::Invalid(Rule::ShortVowel)
+        }
+        (ACUTE, 2) | (CIRCUMFLEX, 1) => match ultima {
+            Quantity::Long => Verdict::Invalid(Rule::Limitation),
+            Quantity::Short => Verdict::Valid,
+            Quantity::Undetermined => Verdict::Undetermined,
+        },
+        (ACUTE, 1) => match (greek::quantity(word, &nuclei[position], false), ultima) {
+            (Quantity::Long, Quantity::Short) => Verdict::Invalid(Rule::Sotera),
+            (Quantity::Short, _) | (_, Quantity::Long) => Verdict::Valid,
+            _ => Verdict::Undetermined,
+        },
+        _ => Verdict::Valid,
+    }
+}
+
+/// Checks whether the accents of a Beta Code or Unicode text follow the rules of
+/// Greek accentuation, word by word.
+///
+/// Only the first accent of each word is checked, so that the secondary acute
+/// brought by an enclitic (ἄνθρωπός τις) is accepted.
+///
+/// ```
+/// assert!(betacode::accentuation::check_accents("a)/nqrwpos").is_ok());
+/// assert!(betacode::accentuation::check_accents("δῶρον").is_ok());
+/// ```
+///
+/// A circumflex on the antepenult or a proparoxytone with a long ultima is
+/// reported with the offending words, as [validate](super::validator::validate) does.
+///
+/// ```
+/// let result = betacode::accentuation::check_accents("a)/nqrwpou");
+/// match result {
+///     Ok(_) => panic!(),
+///     Err(e) => {
+///         if let betacode::accentuation::AccentuationError::LawOfLimitation(b) = e {
+///             assert_eq!(b, vec!["a)/nqrwpou".to_string()]);
+///         }
+///     }
+/// }
+/// ```
+///
+/// When the outcome depends on the length of an unmarked `α`, `ι` or `υ`, the word is
+/// reported as [AccentuationError::Undetermined] instead of guessing.
+///
+/// ```
+/// let result = betacode::accentuation::check_accents("xw/ra");
+/// assert!(matches!(
+///     result,
+///     Err(betacode::accentuation::AccentuationError::Undetermined(_))
+/// ));
+/// assert!(betacode::accentuation::check_accents("xw/ra%26").is_ok());
+/// ```
+///
+/// A single error is returned: the rule broken by the first invalid word, with every
+/// word of the text breaking that same rule. Each word reports only the first rule it
+/// breaks, so a word violating several rules is listed under one of them, and words
+/// breaking other rules are only reported once the first ones are fixed.
+///
+/// ```
+/// use betacode::accentuation::{check_accents, AccentuationError};
+/// // a)=nqrwpou breaks other rules, but lo=gos is the first invalid word
+/// let result = check_accents("lo=gos a)=nqrwpou");
+/// assert!(matches!(
+///     result,
+///     Err(AccentuationError::CircumflexOnShortVowel(words)) if words == vec!["lo=gos"]
+/// ));
+/// ```
+pub fn check_accents<T: Into<String>>(input: T) -> Result<(), AccentuationError> {
+    let input: String = input.into();
+    let mut invalid: Vec<(Rule, String)> = vec![];
+    let mut undetermined: Vec<String> = vec![];
+
+    for token in input.split_whitespace() {
+        let token = token.trim_matches(|c| PUNCTUATION.contains(&c));
+        let word = greek::letters(greek::to_unicode(token));
+        match check_word(&word) {
+            Verdict::Valid => (),
+            Verdict::Invalid(rule) => invalid.push((rule, token.to_string())),
+            Verdict::Undetermined => undetermined.push(token.to_string()),
+        }
+    }
+
+    if let Some((rule, _)) = invalid.first() {
+        let words = invalid
+            .iter()
+            .filter(|(r, _)| r == rule)
+            .map(|(_, w)| w.clone())
+            .collect();
+        return Err(rule.error(words));
+    }
+    match undetermined.is_empty() {
+        true => Ok(()),
+        false => Err(AccentuationError::Undetermined(undetermined)),
+    }
+}
+/// Accent marks, written `/`, `\\` and `=` in Beta Code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Accent {
+    Acute,
+    Grave,
+    Circumflex,
+}
+
+impl Accent {
+    fn beta(self) -> char {
+        match self {
+            Accent::Acute => '/',
+            Accent::Grave => '\\',
+            Accent::Circumflex => '=',
+        }
+    }
+
+    fn mark(self) -> char {
+        match self {
+            Accent::Acute => ACUTE,
+            Accent::Grave => GRAVE,
+            Accent::Circumflex => CIRCUMFLEX,
+        }
+    }
+}
+
+/// Classes of accented words, by the accent and the syllable that bears it.
+/// A grave on the ultima stands for an acute, so the word is an oxytone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccentClass {
+    Oxytone,
+    Paroxytone,
+    Proparoxytone,
+    Perispomenon,
+    Properispomenon,
+}
+
+/// Classifies a Beta Code or Unicode word by its (first) accent.
+///
+/// Returns `None` for unaccented words and for accents on a syllable where no class applies.
+///
+/// ```
+/// use betacode::accentuation::{classify, AccentClass};
+/// assert_eq!(classify("a)/nqrwpos"), Some(AccentClass::Proparoxytone));
+/// assert_eq!(classify("δῶρον"), Some(AccentClass::Properispomenon));
+/// assert_eq!(classify("θεὰ"), Some(AccentClass::Oxytone));
+/// assert_eq!(classify("kai"), None);
+/// ```
+pub fn classify<T: Into<String>>(word: T) -> Option<AccentClass> {
+    let word = greek::letters(greek::to_unicode(word));
+    let nuclei = greek::nuclei(&word);
+    let (position, accent) = nuclei.iter().enumerate().find_map(|(p, n)| {
+        word[n.clone()]
+            .iter()
+            .find_map(|l| l.accent())
+            .map(|a| (p, a))
+    })?;
+    match (accent, nuclei.len() - 1 - position) {
+        (ACUTE | GRAVE, 0) => Some(AccentClass::Oxytone),
+        (ACUTE, 1) => Some(AccentClass::Paroxytone),
+        (ACUTE, 2) => Some(AccentClass::Proparoxytone),
+        (CIRCUMFLEX, 0) => Some(AccentClass::Perispomenon),
+        (CIRCUMFLEX, 1) => Some(AccentClass::Properispomenon),
+        _ => None,
+    }
+}
+
+/// A word split into letters without their accents, keeping the units of the
+/// Beta Code source when the word is in Beta Code.
+struct Unaccented {
+    letters: Vec<Letter>,
+    beta_units: Option<Vec<String>>,
+}
+
+fn unaccented(word: &str) -> Unaccented {
+    match greek::is_betacode(word) {
+        true => {
+            let word = word.replace(['/', '\\', '='], "");
+            let units: Vec<String> = letter_units(&word)
+                .into_iter()
+                .map(|u| word[u].to_string())
+                .collect();
+            let letters = units
+                .iter()
+                .map(|u| {
+                    greek::letters(convert(u.as_str()))
+                        .into_iter()
+                        .next()
+                        .unwrap_or(Letter {
+                            base: ' ',
+                            marks: vec![],
+                        })
+                })
+                .collect();
+            Unaccented {
+                letters,
+                beta_units: Some(units),
+            }
+        }
+        false => {
+            let mut letters = greek::letters(word);
+            letters
+                .iter_mut()
+                .for_each(|l| l.marks.retain(|m| ![ACUTE, GRAVE, CIRCUMFLEX].contains(m)));
+            Unaccented {
+                letters,
+                beta_units: None,
+            }
+        }
+    }
+}
+
+impl Unaccented {
+    /// Writes the word with the accent on the last letter of the given nucleus.
+    fn render(&self, nucleus: &std::ops::Range<usize>, accent: Accent) -> String {
+        let target = nucleus.end - 1;
+        match &self.beta_units {
+            Some(units) => {
+                let mut output = String::new();
+                for (i, unit) in units.iter().enumerate() {
+                    if i != target {
+                        output.push_str(unit);
+                        continue;
+                    }
+                    // The accent follows the breathing and the diaeresis, which precede
+                    // the letter in capitals
+                    let at = match unit.rfind([')', '(', '+']) {
+                        Some(p) => p + 1,
+                        None => match unit.starts_with('*') {
+                            true => 1,
+                            false => unit.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(0) + 1,
+                        },
+                    };
+                    output.push_str(&unit[..at]);
+                    output.push(accent.beta());
+                    output.push_str(&unit[at..]);
+                }
+                output
+            }
+            None => {
+                let mut letters = self.letters.clone();
+                letters[target].marks.push(accent.mark());
+                greek::render(&letters)
+            }
+        }
+    }
+}
+
+/// Places an accent on a Beta Code or Unicode word, counting the syllables from the
+/// end (`0` for the ultima), and removes its other accents. The encoding of the word is kept.
+///
+/// Returns `None` when the word has no such syllable. The placement is not checked
+/// against the rules, see [check_accents].
+///
+/// ```
+/// use betacode::accentuation::{place_accent, Accent};
+/// assert_eq!(place_accent("lo/gos", 0, Accent::Acute).unwrap(), "logo/s");
+/// assert_eq!(place_accent("*)anqrwpos", 2, Accent::Acute).unwrap(), "*)/anqrwpos");
+/// assert_eq!(place_accent("δωρον", 1, Accent::Circumflex).unwrap(), "δῶρον");
+/// assert!(place_accent("lo/gos", 2, Accent::Acute).is_none());
+/// ```
+pub fn place_accent<T: Into<String>>(word: T, syllable: usize, accent: Accent) -> Option<String> {
+    let word = unaccented(&word.into());
+    let nuclei = greek::nuclei(&word.letters);
+    let position = nuclei.len().checked_sub(syllable + 1)?;
+    Some(word.render(&nuclei[position], accent))
+}
+
+/// Accent on the penult, acute or circumflex by the quantities of the penult and the ultima.
+fn penult_accent(penult: Quantity, ultima: Quantity) -> Option<Accent> {
+    match (penult, ultima) {
+        (Quantity::Short, _) | (_, Quantity::Long) => Some(Accent::Acute),
+        (Quantity::Long, Quantity::Short) => Some(Accent::Circumflex),
+        _ => None,
+    }
+}
+
+/// Places the recessive accent on a Beta Code or Unicode word: as far from the end
+/// as the quantity of the ultima allows, as on finite verb forms.
+///
+/// Returns [AccentuationError::Undetermined] when the placement depends on the length
+/// of an unmarked `α`, `ι` or `υ`.
+///
+/// ```
+/// use betacode::accentuation::recessive_accent;
+/// assert_eq!(recessive_accent("e)luomen").unwrap(), "e)lu/omen");
+/// assert_eq!(recessive_accent("παιδευομεν").unwrap(), "παιδεύομεν");
+/// assert_eq!(recessive_accent("paideuw").unwrap(), "paideu/w");
+/// assert_eq!(recessive_accent("δωρον").unwrap(), "δῶρον");
+/// ```
+pub fn recessive_accent<T: Into<String>>(word: T) -> Result<String, AccentuationError> {
+    let input: String = word.into();
+    let word = unaccented(&input);
+    let nuclei = greek::nuclei(&word.letters);
+    let undetermined = || AccentuationError::Undetermined(vec![input.clone()]);
+    let ultima = match nuclei.last() {
+        Some(n) => greek::quantity(&word.letters, n, true),
+        None => return Ok(input.clone()),
+    };
+    let (position, accent) = match (nuclei.len(), ultima) {
+        (1, _) => match greek::quantity(&word.letters, &nuclei[0], false) {
+            Quantity::Long => (0, Accent::Circumflex),
+            Quantity::Short => (0, Accent::Acute),
+            Quantity::Undetermined => return Err(undetermined()),
+        },
+        (_, Quantity::Undetermined) => return Err(undetermined()),
+        (n, Quantity::Short) if n >= 3 => (n - 3, Accent::Acute),
+        (n, Quantity::Long) => (n - 2, Accent::Acute),
+        (n, _) => {
+            let penult = greek::quantity(&word.letters, &nuclei[n - 2], false);
+            match penult_accent(penult, ultima) {
+                Some(accent) => (n - 2, accent),
+                None => return Err(undetermined()),
+            }
+        }
+    };
+    Ok(word.render(&nuclei[position], accent))
+}
+
+/// Places the persistent accent on a Beta Code or Unicode word form: on the syllable
+/// accented in the lemma, counting from the beginning, as far as the ultima of the form
+/// allows. The circumflex of the oxytones in the genitive and dative of the first and
+/// second declensions (θεοῦ) is not predicted.
+///
+/// ```
+/// use betacode::accentuation::persistent_accent;
+/// assert_eq!(persistent_accent("anqrwpou", "a)/nqrwpos").unwrap(), "anqrw/pou");
+/// assert_eq!(persistent_accent("δωρου", "δῶρον").unwrap(), "δώρου");
+/// assert_eq!(persistent_accent("a)nqrwpoi", "a)/nqrwpos").unwrap(), "a)/nqrwpoi");
+/// ```
+pub fn persistent_accent<T: Into<String>, U: Into<String>>(
+    form: T,
+    lemma: U,
+) -> Result<String, AccentuationError> {
+    let input: String = form.into();
+    let lemma = greek::letters(greek::to_unicode(lemma));
+    let (position, lemma_accent) = greek::nuclei(&lemma)
+        .iter()
+        .enumerate()
+        .find_map(|(p, n)| {
+            lemma[n.clone()]
+                .iter()
+                .find_map(|l| l.accent())
+                .map(|a| (p, a))
+        })
+        .unwrap_or((0, ACUTE));
+    let word = unaccented(&input);
+    let nuclei = greek::nuclei(&word.letters);
+    let undetermined = || AccentuationError::Undetermined(vec![input.clone()]);
+    let ultima = match nuclei.last() {
+        Some(n) => greek::quantity(&word.letters, n, true),
+        None => return Ok(input.clone()),
+    };
+    let last = nuclei.len() - 1;
+    let mut from_end = last - position.min(last);
+    if from_end >= 2 && ultima != Quantity::Short {
+        match ultima {
+            Quantity::Long => from_end = 1,
+            _ => return Err(undetermined()),
+        }
+    }
+    let accent = match from_end {
+        0 => match (lemma_accent, ultima) {
+            (CIRCUMFLEX, Quantity::Short) => Accent::Acute,
+            (CIRCUMFLEX, _) => Accent::Circumflex,
+            _ => Accent::Acute,
+        },
+        1 => {
+            let penult = greek::quantity(&word.letters, &nuclei[last - 1], false);
+            match penult_accent(penult, ultima) {
+                Some(accent) => accent,
+                None => return Err(undetermined()),
+            }
+        }
+        _ => Accent::Acute,
+    };
+    Ok(word.render(&nuclei[last - from_end], accent))
+}
+
+/// Options of [citation_form_with].
+#[derive(Debug, Clone, Default)]
+pub struct CitationOptions {
+    /// Lowercases the first letter of each word.
+    pub lowercase: bool,
+}
+
+fn beta_citation_form(word: &str, options: &CitationOptions) -> String {
+    let mut output = word.replace('\\', "/");
+    let accents: Vec<usize> = output.match_indices(['/', '=']).map(|(i, _)| i).collect();
+    if let [_, .., last] = accents[..] {
+        output.remove(last);
+    }
+    if options.lowercase {
+        output = RE_BETA_CAPITAL.replace(&output, "$2$1").to_string();
+    }
+    output
+}
+
+fn unicode_citation_form(word: &str, options: &CitationOptions) -> String {
+    let mut letters = greek::letters(word);
+    for letter in letters.iter_mut() {
+        letter.marks.iter_mut().for_each(|m| {
+            if *m == GRAVE {
+                *m = ACUTE
+            }
+        });
+    }
+    let accented: Vec<usize> = (0..letters.len())
+        .filter(|i| letters[*i].accent().is_some())
+        .collect();
+    if let [_, .., last] = accented[..] {
+        letters[last]
+            .marks
+            .retain(|m| ![ACUTE, CIRCUMFLEX].contains(m));
+    }
+    if options.lowercase {
+        if let Some(first) = letters.iter_mut().find(|l| l.is_greek()) {
+            first.base = first.base.to_lowercase().next().unwrap_or(first.base);
+        }
+    }
+    greek::render(&letters)
+}
+
+/// Brings the words of a Beta Code or Unicode text to their citation form, keeping
+/// the encoding of the input.
+///
+/// Graves become acutes (θεὰ → θεά), and the secondary acute brought by an
+/// enclitic is removed (ἄνθρωπός → ἄνθρωπος).
+///
+/// ```
+/// use betacode::accentuation::citation_form;
+/// assert_eq!(citation_form("qea\\"), "qea/");
+/// assert_eq!(citation_form("ἄνθρωπός τις"), "ἄνθρωπος τις");
+/// ```
+pub fn citation_form<T: Into<String>>(input: T) -> String {
+    citation_form_with(input, &CitationOptions::default())
+}
+
+/// Brings the words of a Beta Code or Unicode text to their citation form with the
+/// given options.
+///
+/// ```
+/// use betacode::accentuation::{citation_form_with, CitationOptions};
+/// let options = CitationOptions { lowercase: true };
+/// assert_eq!(citation_form_with("*)axilleu\\s", &options), "a)xilleu/s");
+/// assert_eq!(citation_form_with("Σωκράτης", &options), "σωκράτης");
+/// ```
+pub fn citation_form_with<T: Into<String>>(input: T, options: &CitationOptions) -> String {
+    let input: String = input.into();
+    let betacode = greek::is_betacode(&input);
+    RE_WORD
+        .replace_all(&input, |c: &Captures| match betacode {
+            true => beta_citation_form(&c[0], options),
+            false => unicode_citation_form(&c[0], options),
+        })
+        .to_string()
+}
+#[cfg(test)]
+mod test;
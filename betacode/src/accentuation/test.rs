@@ -0,0 +1,132 @@
+use super::*;
+#[test]
+fn valid_accents() {
+    assert!(check_accents("a)/nqrwpos").is_ok());
+    assert!(check_accents("a)/nqrwpo/s tis").is_ok());
+    assert!(check_accents("dw=ron lo/gou qea\\").is_ok());
+    assert!(check_accents("δῶρον ἄνθρωποι").is_ok());
+}
+#[test]
+fn invalid_accents() {
+    let result = check_accents("a)=nqrwpos");
+    assert!(matches!(
+        result,
+        Err(AccentuationError::CircumflexBeyondPenult(_))
+    ));
+    let result = check_accents("a)/nqrwpos xw/ra");
+    assert!(matches!(result, Err(AccentuationError::Undetermined(_))));
+    let result = check_accents("e)/nanqrwpos");
+    assert!(matches!(
+        result,
+        Err(AccentuationError::AcuteBeyondAntepenult(_))
+    ));
+    let result = check_accents("lo=gos");
+    assert!(matches!(
+        result,
+        Err(AccentuationError::CircumflexOnShortVowel(_))
+    ));
+    let result = check_accents("dw/ron");
+    assert!(matches!(result, Err(AccentuationError::Sotera(_))));
+    let result = check_accents("dw=rou");
+    assert!(matches!(result, Err(AccentuationError::LawOfLimitation(_))));
+    let result = check_accents("lo\\gos");
+    assert!(matches!(
+        result,
+        Err(AccentuationError::GraveNotOnUltima(_))
+    ));
+}
+#[test]
+fn undetermined_quantity() {
+    match check_accents("xw/ra ou)/k") {
+        Err(AccentuationError::Undetermined(b)) => assert_eq!(b, vec!["xw/ra".to_string()]),
+        _ => panic!(),
+    }
+    assert!(check_accents("xw/ra%26").is_ok());
+    assert!(check_accents("ne/a%27").is_ok());
+}
+#[test]
+fn citation_forms() {
+    assert_eq!(
+        citation_form("mh=nin a)/eide qea\\ *phlhi+a/dew"),
+        "mh=nin a)/eide qea/ *phlhi+a/dew"
+    );
+    assert_eq!(citation_form("μῆνιν ἄειδε θεὰ"), "μῆνιν ἄειδε θεά");
+    assert_eq!(citation_form("a)/nqrwpo/s tis"), "a)/nqrwpos tis");
+    assert_eq!(citation_form("σῶμά τι"), "σῶμα τι");
+    assert_eq!(citation_form("Ἀχιλλεὺς"), "Ἀχιλλεύς");
+}
+#[test]
+fn citation_lowercase() {
+    let options = CitationOptions { lowercase: true };
+    assert_eq!(citation_form_with("*(/a|dhs", &options), "a(/|dhs");
+    assert_eq!(citation_form_with("*phlhi+a/dew", &options), "phlhi+a/dew");
+    assert_eq!(citation_form_with("Ἀχιλλεὺς", &options), "ἀχιλλεύς");
+}
+#[test]
+fn classes() {
+    assert_eq!(classify("logo/s"), Some(AccentClass::Oxytone));
+    assert_eq!(classify("lo/gos"), Some(AccentClass::Paroxytone));
+    assert_eq!(classify("ἄνθρωπός"), Some(AccentClass::Proparoxytone));
+    assert_eq!(classify("qeou="), Some(AccentClass::Perispomenon));
+    assert_eq!(classify("Μοῦσα"), Some(AccentClass::Properispomenon));
+    assert_eq!(classify("e)/nanqrwpos"), None);
+}
+#[test]
+fn placement() {
+    assert_eq!(
+        place_accent("*)anqrwpos", 2, Accent::Acute).unwrap(),
+        "*)/anqrwpos"
+    );
+    assert_eq!(
+        place_accent("oi)kos", 1, Accent::Circumflex).unwrap(),
+        "oi)=kos"
+    );
+    assert_eq!(
+        place_accent("a)i+dios", 2, Accent::Acute).unwrap(),
+        "a)i+/dios"
+    );
+    assert_eq!(place_accent("tw|", 0, Accent::Circumflex).unwrap(), "tw=|");
+    assert_eq!(place_accent("θεά", 0, Accent::Grave).unwrap(), "θεὰ");
+    assert_eq!(place_accent("οἶκος", 0, Accent::Acute).unwrap(), "οἰκός");
+}
+#[test]
+fn recessive() {
+    assert_eq!(recessive_accent("e)paideuon").unwrap(), "e)pai/deuon");
+    assert_eq!(recessive_accent("paideuetai").unwrap(), "paideu/etai");
+    assert_eq!(recessive_accent("luete").unwrap(), "lu/ete");
+    assert_eq!(recessive_accent("πεμπε").unwrap(), "πέμπε");
+    assert_eq!(recessive_accent("ἡκε").unwrap(), "ἧκε");
+    assert!(matches!(
+        recessive_accent("lue"),
+        Err(AccentuationError::Undetermined(_))
+    ));
+}
+#[test]
+fn persistent() {
+    assert_eq!(
+        persistent_accent("a)nqrwpwn", "a)/nqrwpos").unwrap(),
+        "a)nqrw/pwn"
+    );
+    assert_eq!(persistent_accent("δωροις", "δῶρον").unwrap(), "δώροις");
+    assert_eq!(persistent_accent("dwra%27", "δῶρον").unwrap(), "dw=ra%27");
+    assert_eq!(persistent_accent("qeon", "qeo/s").unwrap(), "qeo/n");
+}
+#[test]
+fn first_error_only() {
+    // Breaks both the circumflex placement and the law of limitation
+    match check_accents("a)=nqrwpou") {
+        Err(AccentuationError::CircumflexBeyondPenult(b)) => assert_eq!(b, vec!["a)=nqrwpou"]),
+        _ => panic!(),
+    }
+    // Only the words breaking the rule of the first invalid word are listed
+    match check_accents("dw/ron e)/nanqrwpos dw/rou") {
+        Err(AccentuationError::Sotera(b)) => assert_eq!(b, vec!["dw/ron"]),
+        _ => panic!(),
+    }
+    match check_accents("e)/nanqrwpos dw/ron e)/pipolemos") {
+        Err(AccentuationError::AcuteBeyondAntepenult(b)) => {
+            assert_eq!(b, vec!["e)/nanqrwpos", "e)/pipolemos"])
+        }
+        _ => panic!(),
+    }
+}
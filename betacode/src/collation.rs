@@ -0,0 +1,100 @@
+use crate::greek::{
+    self, Letter, ACUTE, BREVE, CIRCUMFLEX, DIAERESIS, GRAVE, IOTA_SUBSCRIPT, MACRON, ROUGH, SMOOTH,
+};
+use std::cmp::Ordering;
+
+/// Greek alphabet in dictionary order, the numeral letters at their
+/// traditional places. Letters sharing a slot share the same primary weight.
+const ALPHABET: [&str; 27] = [
+    "α", "β", "γ", "δ", "ε", "ϝϛ", "ζ", "η", "θ", "ι", "κ", "λ", "μ", "ν", "ξ", "ο", "π", "ϟϙ",
+    "ρ", "σ", "τ", "υ", "φ", "χ", "ψ", "ω", "ϡ",
+];
+
+/// Sort key of a Greek word or text, produced by [collation_key].
+///
+/// Keys compare as dictionary order:
+/// - `primary`: the base letters, with all the forms of sigma as `σ`;
+/// - `secondary`: the diacritics, unmarked letters first;
+/// - `tertiary`: the case, lowercase first.
+///
+/// Non-Greek characters sort after the Greek letters, by code point.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CollationKey {
+    pub primary: Vec<u32>,
+    pub secondary: Vec<u32>,
+    pub tertiary: Vec<u32>,
+}
+
+fn primary(letter: &Letter) -> u32 {
+    let base = letter.lower();
+    ALPHABET
+        .iter()
+        .position(|slot| slot.contains(base))
+        .map(|p| p as u32 + 1)
+        .unwrap_or(ALPHABET.len() as u32 + 1 + base as u32)
+}
+
+/// Weight of the diacritics of a letter: breathing, then accent, then the other marks.
+fn secondary(letter: &Letter) -> u32 {
+    let breathing = match letter.breathing() {
+        Some(SMOOTH) => 1,
+        Some(ROUGH) => 2,
+        _ => 0,
+    };
+    let accent = match letter.accent() {
+        Some(ACUTE) => 1,
+        Some(GRAVE) => 2,
+        Some(CIRCUMFLEX) => 3,
+        _ => 0,
+    };
+    let others = [DIAERESIS, IOTA_SUBSCRIPT, MACRON, BREVE]
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| letter.has(**m))
+        .map(|(i, _)| 1 << (3 - i))
+        .sum::<u32>();
+    breathing * 64 + accent * 16 + others
+}
+
+/// Computes the collation key of a Beta Code or Unicode word or text.
+///
+/// ```
+/// use betacode::collation::collation_key;
+/// assert_eq!(collation_key("λόγος").primary, collation_key("λογοσ").primary);
+/// assert!(collation_key("λογος") < collation_key("λόγος"));
+/// assert_eq!(collation_key("*)a/nqrwpos"), collation_key("Ἄνθρωπος"));
+/// ```
+pub fn collation_key<T: Into<String>>(input: T) -> CollationKey {
+    let letters = greek::letters(greek::to_unicode(input));
+    CollationKey {
+        primary: letters.iter().map(primary).collect(),
+        secondary: letters.iter().map(secondary).collect(),
+        tertiary: letters.iter().map(|l| l.is_upper() as u32).collect(),
+    }
+}
+
+/// Compares two Beta Code or Unicode words or texts in dictionary order.
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use betacode::collation::compare;
+/// assert_eq!(compare("ἄλφα", "βῆτα"), Ordering::Less);
+/// assert_eq!(compare("*)a/nqrwpos", "ἀνήρ"), Ordering::Greater);
+/// assert_eq!(compare("ὅς", "ὁσ"), Ordering::Greater);
+/// ```
+pub fn compare<T: Into<String>, U: Into<String>>(a: T, b: U) -> Ordering {
+    collation_key(a).cmp(&collation_key(b))
+}
+
+/// Sorts Beta Code or Unicode words in dictionary order.
+///
+/// ```
+/// let mut words = vec!["ὧν", "ἄλφα", "ὠ", "*a)/lfa", "ἀλφά"];
+/// betacode::collation::sort(&mut words);
+/// assert_eq!(words, vec!["ἀλφά", "ἄλφα", "*a)/lfa", "ὠ", "ὧν"]);
+/// ```
+pub fn sort<T: AsRef<str>>(words: &mut [T]) {
+    words.sort_by_cached_key(|w| collation_key(w.as_ref()));
+}
+#[cfg(test)]
+mod test;
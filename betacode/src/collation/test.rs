@@ -0,0 +1,30 @@
+use super::*;
+#[test]
+fn base_letters_first() {
+    assert_eq!(compare("ἄγω", "αγω"), Ordering::Greater);
+    assert_eq!(compare("ἄγω", "αδω"), Ordering::Less);
+    assert_eq!(compare("ῥόδον", "ρωμη"), Ordering::Less);
+}
+#[test]
+fn sigma_forms() {
+    assert_eq!(compare("λόγος", "λόγοσ"), Ordering::Equal);
+    assert_eq!(compare("lo/gos3", "λόγος"), Ordering::Equal);
+    assert_eq!(compare("λόγος", "λόγοτ"), Ordering::Less);
+}
+#[test]
+fn capitals() {
+    assert_eq!(compare("*(/omhros", "ὅμηρος"), Ordering::Greater);
+    assert_eq!(compare("*(/omhros", "ὁμός"), Ordering::Less);
+}
+#[test]
+fn sorting() {
+    let mut words = vec![
+        String::from("ψυχή"),
+        String::from("ἀγαθός"),
+        String::from("*zeu/s"),
+        String::from("ἄγαν"),
+        String::from("ϝάναξ"),
+    ];
+    sort(&mut words);
+    assert_eq!(words, vec!["ἀγαθός", "ἄγαν", "ϝάναξ", "*zeu/s", "ψυχή"]);
+}
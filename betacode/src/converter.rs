@@ -1,18 +1,24 @@
+use crate::greek::{self, Letter, IOTA_SUBSCRIPT};
+use crate::numerals::{arabic_to_beta, KERAIA, LOWER_KERAIA};
+use crate::syllables::hyphenate;
+use crate::tokenizer::letter_units;
+use crate::uppercase::greek_uppercase;
 use crate::validator::mixed_case;
 use lazy_static::lazy_static;
 use log::warn;
-use regex::Regex;
+use regex::{Captures, Regex};
 use std::collections::HashMap;
+use std::ops::Range;
 use unicode_normalization::UnicodeNormalization;
 
-const BETA_MID_VALUES: [&str; 68] = [
+pub(crate) const BETA_MID_VALUES: [&str; 72] = [
     ")", "(", "/", "=", "\\", "+", "|", "A", "a", "B", "b", "C", "c", "D", "d", "E", "e", "F", "f",
     "G", "g", "H", "h", "I", "i", "K", "k", "L", "l", "M", "m", "N", "n", "O", "o", "P", "p", "Q",
     "q", "R", "r", "S", "s", "T", "t", "U", "u", "V", "v", "W", "w", "X", "x", "Y", "y", "Z", "z",
-    ";", ":", "*#1", "#1", "*#2", "#2", "*#3", "#3", "*#5", "#5", "%26",
+    ";", ":", "'", "*#1", "#1", "#22", "*#2", "#2", "*#3", "#3", "*#5", "#5", "#", "%26", "%27",
 ];
 
-const UNI_VALUES: [&str; 68] = [
+pub(crate) const UNI_VALUES: [&str; 72] = [
     "\u{0313}", "\u{0314}", "\u{0301}", "\u{0342}", "\u{0300}", "\u{0308}", "\u{0345}", "\u{0391}",
     "\u{03b1}", "\u{0392}", "\u{03b2}", "\u{039e}", "\u{03be}", "\u{0394}", "\u{03b4}", "\u{0395}",
     "\u{03b5}", "\u{03a6}", "\u{03c6}", "\u{0393}", "\u{03b3}", "\u{0397}", "\u{03b7}", "\u{0399}",
@@ -20,8 +26,8 @@ const UNI_VALUES: [&str; 68] = [
     "\u{03bd}", "\u{039f}", "\u{03bf}", "\u{03a0}", "\u{03c0}", "\u{0398}", "\u{03b8}", "\u{03a1}",
     "\u{03c1}", "\u{03a3}", "\u{03c3}", "\u{03a4}", "\u{03c4}", "\u{03a5}", "\u{03c5}", "\u{03dc}",
     "\u{03dd}", "\u{03a9}", "\u{03c9}", "\u{03a7}", "\u{03c7}", "\u{03a8}", "\u{03c8}", "\u{0396}",
-    "\u{03b6}", "\u{00b3}", "\u{00b7}", "\u{03de}", "\u{03df}", "\u{03da}", "\u{03db}", "\u{03d8}",
-    "\u{03d9}", "\u{03e0}", "\u{03e1}", "\u{0304}",
+    "\u{03b6}", "\u{037e}", "\u{00b7}", "\u{2019}", "\u{03de}", "\u{03df}", "\u{0375}", "\u{03da}",
+    "\u{03db}", "\u{03d8}", "\u{03d9}", "\u{03e0}", "\u{03e1}", "\u{02b9}", "\u{0304}", "\u{0306}",
 ];
 
 lazy_static! {
@@ -46,7 +52,16 @@ lazy_static! {
     static ref RE_UNORDERED_DIACRITICS: Regex = Regex::new(r"(\|*)([\\/=])(\|*)([()\+])").unwrap();
 }
 lazy_static! {
-    static ref RE_FINAL_SIGMA_CHAR: Regex = Regex::new(r"σ([2 .,·;’‐—\n])").unwrap();
+    static ref RE_FINAL_SIGMA_CHAR: Regex = Regex::new(r"σ([2 .,·;\x{037e}‐—\n])").unwrap();
+}
+lazy_static! {
+    static ref RE_LINE_HYPHEN: Regex = Regex::new(
+        r"([\p{Greek}\p{M}])-[ \t]*(\r?\n)[ \t]*((?:\S*[\p{Greek}\p{M}]-[ \t]*\r?\n[ \t]*)*\S+)[ \t]*(\r?\n|$)?"
+    )
+    .unwrap();
+}
+lazy_static! {
+    static ref RE_LINE_BREAK: Regex = Regex::new(r"([\p{Greek}\p{M}])-[ \t]*\r?\n[ \t]*").unwrap();
 }
 lazy_static! {
     static ref RE_FINAL_SIGMA_END: Regex = Regex::new(r"σ$").unwrap();
@@ -54,11 +69,11 @@ lazy_static! {
 
 const SPECIAL_SIGMAS: [&str; 3] = ["σ1", "σ3", "Σ3"];
 
-fn compose_unicode<T: Into<String>>(input: T) -> String {
+pub(crate) fn compose_unicode<T: Into<String>>(input: T) -> String {
     let input: &str = &input.into();
     input.nfkc().collect::<String>()
 }
-fn decompose_unicode<T: Into<String>>(input: T) -> String {
+pub(crate) fn decompose_unicode<T: Into<String>>(input: T) -> String {
     let input: &str = &input.into();
     input.nfkd().collect::<String>()
 }
@@ -88,19 +103,17 @@ pub fn find_upper<T: Into<String>>(input: T) -> String {
 
     let mut output = String::new();
     for (i, character) in ascii_enum.iter().enumerate() {
-        if character == &'*' && ascii_chars[i + 1].is_alphabetic() {
+        let at = |j: usize| ascii_chars.get(i + j).copied().unwrap_or(' ');
+        if character == &'*' && at(1).is_alphabetic() {
             ascii_chars[i + 1] = ascii_chars[i + 1].to_ascii_uppercase();
-        } else if character == &'*'
-            && [')', '('].contains(&ascii_chars[i + 1])
-            && ascii_chars[i + 2].is_alphabetic()
-        {
+        } else if character == &'*' && [')', '('].contains(&at(1)) && at(2).is_alphabetic() {
             let diacritic = ascii_chars[i + 1];
             ascii_chars[i + 1] = ascii_chars[i + 2].to_ascii_uppercase();
             ascii_chars[i + 2] = diacritic;
         } else if character == &'*'
-            && [')', '('].contains(&ascii_chars[i + 1])
-            && ['=', '/'].contains(&ascii_chars[i + 2])
-            && ascii_chars[i + 3].is_alphabetic()
+            && [')', '('].contains(&at(1))
+            && ['=', '/'].contains(&at(2))
+            && at(3).is_alphabetic()
         {
             let spirit = ascii_chars[i + 1];
             let accent = ascii_chars[i + 2];
@@ -170,6 +183,81 @@ fn unicode_to_ascii<T: Into<String>>(input: T) -> String {
     output
 }
 
+/// Rejoins the Greek words broken with a hyphen at the end of a line (or across several
+/// lines), optionally leaving a marker at each break.
+///
+/// When the rejoined word was followed by more text on its last line, the line break
+/// is moved after the word; otherwise the line break ending that line is kept.
+fn dehyphenate<T: Into<String>>(input: T, marker: Option<char>) -> String {
+    let input: String = input.into();
+    let marker = marker.map(String::from).unwrap_or_default();
+    RE_LINE_HYPHEN
+        .replace_all(&input, |c: &Captures| {
+            let rest = RE_LINE_BREAK.replace_all(&c[3], format!("${{1}}{marker}"));
+            // Keeps the line end of the text, even where it moved the rest of the line
+            let line_end = c.get(4).map_or(&c[2], |m| m.as_str());
+            format!("{}{}{}{}", &c[1], marker, rest, line_end)
+        })
+        .to_string()
+}
+
+/// Writes the iota subscripts as adscripts where the mode requires it.
+fn iota_adscript<T: Into<String>>(input: T, mode: IotaAdscript) -> String {
+    let mut output = vec![];
+    for mut letter in greek::letters(input) {
+        let adscript = letter.has(IOTA_SUBSCRIPT)
+            && (mode == IotaAdscript::Everywhere
+                || (mode == IotaAdscript::Capitals && letter.is_upper()));
+        if adscript {
+            letter.marks.retain(|m| *m != IOTA_SUBSCRIPT);
+        }
+        output.push(letter);
+        if adscript {
+            output.push(Letter {
+                base: 'ι',
+                marks: vec![],
+            });
+        }
+    }
+    greek::render(&output)
+}
+
+/// Writes as iota subscripts the adscripts recognized by [revert_with]: a small `ι`
+/// without diacritics after a capital `Α`, `Η` or `Ω` bearing a breathing or an accent
+/// (which in a diphthong would be on the iota), and with `lowercase` the same after a
+/// small `α`, `η` or `ω`, or a word-final `ι` after `η` or `ω`.
+/// Greek numerals are left as they are.
+fn adscript_to_subscript<T: Into<String>>(input: T, lowercase: bool) -> String {
+    let letters = greek::letters(input);
+    let mut output: Vec<Letter> = vec![];
+    for (i, letter) in letters.iter().enumerate() {
+        let numeral = || {
+            let end = (i..letters.len()).find(|j| !letters[*j].is_greek());
+            let start = (0..i).rev().find(|j| !letters[*j].is_greek());
+            end.is_some_and(|e| letters[e].base == KERAIA)
+                || start.is_some_and(|s| letters[s].base == LOWER_KERAIA)
+        };
+        let word_final = letters.get(i + 1).is_none_or(|l| !l.is_greek());
+        let adscript = letter.base == 'ι'
+            && letter.marks.is_empty()
+            && output.last().is_some_and(|p| {
+                let marked = p.accent().is_some() || p.breathing().is_some();
+                let vowel = matches!(p.lower(), 'α' | 'η' | 'ω') && !p.has(IOTA_SUBSCRIPT);
+                match p.is_upper() {
+                    true => vowel && marked,
+                    false if lowercase => vowel && (marked || (word_final && p.lower() != 'α')),
+                    false => false,
+                }
+            })
+            && !numeral();
+        match (adscript, output.last_mut()) {
+            (true, Some(previous)) => previous.marks.push(IOTA_SUBSCRIPT),
+            _ => output.push(letter.clone()),
+        }
+    }
+    greek::render(&output)
+}
+
 /// Handles the specific rules for final sigmas.
 ///
 pub fn sigma_handler<T: Into<String>>(input: T) -> String {
@@ -189,11 +277,60 @@ pub fn special_sigma<T: Into<String>>(input: T) -> String {
         .replace("Σ3", "\u{03f9}")
 }
 
+/// Characters used for the elision mark (`'` in Beta Code) by [convert_with].
+///
+/// - [Elision::Apostrophe]: right single quotation mark (U+2019), the default.
+/// - [Elision::Koronis]: Greek koronis (U+1FBD).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Elision {
+    #[default]
+    Apostrophe,
+    Koronis,
+}
+
+/// Letters on which [convert_with] writes the iota subscript as an adscript.
+///
+/// - [IotaAdscript::Never]: always as a subscript (ypogegrammeni), the default.
+/// - [IotaAdscript::Capitals]: as an adscript after capitals (`Αι` for `ᾼ`), as a subscript elsewhere.
+/// - [IotaAdscript::Everywhere]: always as an adscript (`ωι` for `ῳ`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IotaAdscript {
+    #[default]
+    Never,
+    Capitals,
+    Everywhere,
+}
+
+/// Optional steps of the conversion pipeline, used by [convert_with].
+///
+/// The default options reproduce [convert].
+#[derive(Debug, Clone, Default)]
+pub struct ConvertOptions {
+    /// Inserts soft hyphens (U+00AD) between syllables, as [hyphenate](crate::syllables::hyphenate).
+    pub soft_hyphens: bool,
+    /// Writes the Arabic numbers of the text as Greek numerals, as
+    /// [to_greek_numeral](crate::numerals::to_greek_numeral).
+    pub numerals: bool,
+    /// Character written for the elision mark.
+    pub elision: Elision,
+    /// Rejoins the words hyphenated at the end of a line, moving the line break after the word.
+    pub dehyphenate: bool,
+    /// Character left where a rejoined word was broken, so the line layout can be reconstructed.
+    pub hyphen_marker: Option<char>,
+    /// Letters on which the iota subscript is written as an adscript.
+    pub iota_adscript: IotaAdscript,
+    /// Renders the text in capitals, as [to_greek_uppercase](crate::uppercase::to_greek_uppercase).
+    pub all_caps: bool,
+    /// Keeps an all-caps input in capitals, as [all_caps](ConvertOptions::all_caps),
+    /// instead of lowercasing it.
+    pub keep_capitals: bool,
+}
+
 /// Applies the conversion pipeline.
 ///
 /// The conversion pipeline is:
 /// - lower the case of the whole entry if needed;
-///     - text in all upper case will be lowercased
+///     - text in all upper case will be lowercased (or kept in capitals, see [ConvertOptions::keep_capitals])
 /// - substitutes the `*+letter` sequences to upper case letter;
 /// - normalizes the diacritics ordering;
 /// - converts from ascii betacode to unicode Greek;
@@ -201,7 +338,35 @@ pub fn special_sigma<T: Into<String>>(input: T) -> String {
 /// - applies specific conversion rules to sigmas.
 ///
 pub fn convert<T: Into<String>>(input: T) -> String {
+    convert_with(input, &ConvertOptions::default())
+}
+
+/// Applies the conversion pipeline with the optional steps set in [ConvertOptions].
+///
+/// ```
+/// use betacode::converter::{convert_with, ConvertOptions};
+/// let options = ConvertOptions {
+///     soft_hyphens: true,
+///     ..Default::default()
+/// };
+/// let result = convert_with("a)/nqrwpos", &options);
+/// assert_eq!(result, "ἄν\u{00ad}θρω\u{00ad}πος");
+/// let options = ConvertOptions {
+///     numerals: true,
+///     ..Default::default()
+/// };
+/// let result = convert_with("e)/tos 432", &options);
+/// assert_eq!(result, "ἔτος υλβʹ");
+/// let options = ConvertOptions {
+///     dehyphenate: true,
+///     ..Default::default()
+/// };
+/// let result = convert_with("o( lo/-\ngos kai\\ o( mu=qos", &options);
+/// assert_eq!(result, "ὁ λόγος\nκαὶ ὁ μῦθος");
+/// ```
+pub fn convert_with<T: Into<String>>(input: T, options: &ConvertOptions) -> String {
     let mut output = input.into();
+    let mut all_caps = options.all_caps;
 
     // Handles valid mixed case
     match mixed_case(&output) {
@@ -211,6 +376,7 @@ pub fn convert<T: Into<String>>(input: T) -> String {
                 output = find_upper(output);
             } else if output.find(char::is_lowercase).is_none() {
                 output = output.to_lowercase();
+                all_caps |= options.keep_capitals && output.contains(char::is_lowercase);
             }
         }
         Err(_) => {
@@ -218,6 +384,10 @@ pub fn convert<T: Into<String>>(input: T) -> String {
             output = find_upper(output);
         }
     }
+    // Numerals are added in lowercase, after the case handling
+    if options.numerals {
+        output = arabic_to_beta(output);
+    }
     // Checks for unordered diacritics
     if RE_UNORDERED_DIACRITICS.is_match(&output) {
         output = reorder_diacritics(output);
@@ -226,6 +396,10 @@ pub fn convert<T: Into<String>>(input: T) -> String {
     // Main conversion algorithm
     output = ascii_to_unicode(output);
 
+    if options.dehyphenate {
+        output = dehyphenate(output, options.hyphen_marker);
+    }
+
     // Handles final sigma rules
     if RE_FINAL_SIGMA_END.is_match(&output) || RE_FINAL_SIGMA_CHAR.is_match(&output) {
         output = sigma_handler(output);
@@ -239,11 +413,128 @@ pub fn convert<T: Into<String>>(input: T) -> String {
         output = special_sigma(output);
     }
 
+    if all_caps {
+        output = greek_uppercase(&output);
+    } else if options.iota_adscript != IotaAdscript::Never {
+        output = iota_adscript(output, options.iota_adscript);
+    }
+
+    if options.elision == Elision::Koronis {
+        output = output.replace('\u{2019}', "\u{1fbd}");
+    }
+
+    if options.soft_hyphens {
+        output = hyphenate(output);
+    }
+
     output
 }
+/// Correspondence between the byte spans of a Beta Code input and of its conversion,
+/// produced by [convert_with_map].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpanMap {
+    /// Pairs of Beta Code and Unicode byte ranges, one per converted unit, in order.
+    pub segments: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl SpanMap {
+    fn map(&self, span: Range<usize>, from_beta: bool) -> Range<usize> {
+        let pairs: Vec<(&Range<usize>, &Range<usize>)> = self
+            .segments
+            .iter()
+            .map(|(b, g)| match from_beta {
+                true => (b, g),
+                false => (g, b),
+            })
+            .collect();
+        let overlapping: Vec<&Range<usize>> = pairs
+            .iter()
+            .filter(|(from, _)| match span.is_empty() {
+                true => from.contains(&span.start),
+                false => from.start < span.end && span.start < from.end,
+            })
+            .map(|(_, to)| *to)
+            .collect();
+        match (overlapping.first(), overlapping.last()) {
+            (Some(first), Some(last)) => first.start..last.end,
+            _ => {
+                let end = pairs.last().map_or(0, |(_, to)| to.end);
+                end..end
+            }
+        }
+    }
+    /// Maps a byte span of the Beta Code input to the span of the Unicode output it produced.
+    pub fn to_greek(&self, beta: Range<usize>) -> Range<usize> {
+        self.map(beta, true)
+    }
+    /// Maps a byte span of the Unicode output to the span of the Beta Code input it came from.
+    pub fn to_beta(&self, greek: Range<usize>) -> Range<usize> {
+        self.map(greek, false)
+    }
+}
+
+/// Applies the conversion pipeline, returning the output together with a [SpanMap]
+/// between the byte spans of the input and of the output.
+///
+/// Each letter is converted with its diacritics, so the reordering of capitals and
+/// diacritics stays within its span. The output is the same as [convert].
+///
+/// ```
+/// use betacode::converter::convert_with_map;
+/// let input = "*)a/nqrwpos kai\\ qeo/s";
+/// let (output, map) = convert_with_map(input);
+/// assert_eq!(output, "Ἄνθρωπος καὶ θεός");
+/// assert_eq!(&output[map.to_greek(0..11)], "Ἄνθρωπος");
+/// assert_eq!(&input[map.to_beta(0..3)], "*)a/");
+/// ```
+pub fn convert_with_map<T: Into<String>>(input: T) -> (String, SpanMap) {
+    let input: String = input.into();
+    let all_upper = input.find(char::is_lowercase).is_none();
+
+    let mut output = String::new();
+    let mut map = SpanMap::default();
+    for unit in letter_units(&input) {
+        let beta = &input[unit.clone()];
+        // Applies the case handling of the whole input to the unit
+        let beta = if all_upper || beta.contains('*') {
+            beta.to_lowercase()
+        } else if beta.find(char::is_uppercase).is_some() {
+            format!("*{}", beta.to_lowercase())
+        } else {
+            beta.to_string()
+        };
+        // An asterisk not followed by a letter has no capital to mark
+        let mut greek = match beta.as_str() {
+            "*" => beta.clone(),
+            _ => convert(beta.as_str()),
+        };
+        // Final sigmas are set on the whole output
+        if beta.trim_start_matches('*').starts_with('s') && !beta.ends_with(['1', '2', '3']) {
+            greek = greek.replace('ς', "σ");
+        }
+        let start = output.len();
+        output.push_str(&greek);
+        map.segments.push((unit, start..output.len()));
+    }
+    if RE_FINAL_SIGMA_END.is_match(&output) || RE_FINAL_SIGMA_CHAR.is_match(&output) {
+        output = sigma_handler(output);
+    }
+    (output, map)
+}
+/// Optional steps of the reversion pipeline, used by [revert_with].
+///
+/// The default options reproduce [revert].
+#[derive(Debug, Clone, Default)]
+pub struct RevertOptions {
+    /// Also reads as iota subscripts the adscripts after small letters (`τῶι`, `ὠιδή`, `θεῶι`).
+    /// Without it, only the adscripts after a capital are recognized (`Ἅιδης`).
+    pub iota_adscript: bool,
+}
+
 /// Applies the reversion pipeline.
 ///
 /// The reversion pipeline is:
+/// - writes the iota adscripts after capitals (`Ἅιδης`) as subscripts;
 /// - normalize unicode to decomposed structure
 /// - converts from ascii betacode to unicode Greek;
 /// - applies specific conversion rules to sigmas.
@@ -251,14 +542,30 @@ pub fn convert<T: Into<String>>(input: T) -> String {
 /// - lowercases the output.
 ///
 pub fn revert<T: Into<String>>(input: T) -> String {
-    let mut output = input.into();
+    revert_with(input, &RevertOptions::default())
+}
+
+/// Applies the reversion pipeline with the optional steps set in [RevertOptions].
+///
+/// ```
+/// use betacode::converter::{revert, revert_with, RevertOptions};
+/// assert_eq!(revert("τῶι πρωινῶι"), "tw=i prwinw=i");
+/// let options = RevertOptions {
+///     iota_adscript: true,
+/// };
+/// assert_eq!(revert_with("τῶι πρωινῶι", &options), "tw=| prwinw=|");
+/// ```
+pub fn revert_with<T: Into<String>>(input: T, options: &RevertOptions) -> String {
+    let mut output: String = input.into();
+    output = output.replace('\u{1fbd}', "\u{2019}");
+    output = adscript_to_subscript(output, options.iota_adscript);
     output = decompose_unicode(&output);
     output = unicode_to_ascii(output);
     output = output.replace("ς", "s");
     let re = Regex::new(r"([A-Z])").unwrap();
     output = re.replace_all(&output, r"*$1").to_string();
     output = output.to_lowercase();
-    output.into()
+    output
 }
 #[cfg(test)]
 mod tests_converter;
@@ -10,6 +10,13 @@ fn capital_letters() {
     assert_eq!(result, string);
 }
 #[test]
+fn capital_letters_at_end() {
+    assert_eq!(find_upper("*"), "*");
+    assert_eq!(find_upper("a*"), "a*");
+    assert_eq!(find_upper("*("), "*(");
+    assert_eq!(find_upper("*(/"), "*(/");
+}
+#[test]
 fn capital_letters_from_uppercase() {
     let result = find_upper("*A".to_string());
     assert_eq!(result, "A".to_string());
@@ -110,3 +117,182 @@ fn revert_ok() {
     let result = revert(input);
     assert_eq!(result, output);
 }
+#[test]
+fn numeral_signs() {
+    assert_eq!(convert("#22a#5#1#2#"), "͵αϡϟϛʹ");
+    assert_eq!(revert("͵αϡϟϛʹ"), "#22a#5#1#2#");
+    assert_eq!(revert("κγ\u{0374}"), "kg#");
+}
+#[test]
+fn question_mark() {
+    assert_eq!(convert("ti/;"), "τί;");
+    assert_eq!(convert("ti/s;"), "τίς;");
+    assert_eq!(revert("τί;"), "ti/;");
+}
+#[test]
+fn convert_with_map_matches_convert() {
+    for input in [
+        "mh=nin a)/eide qea\\ *phlhi+a/dew *a)xilh=os",
+        "mh=nin a/)eide qea\\ *phlhi+a/dew *a)xilh=os3",
+        "MHNIN AEIDE",
+        "*(/omhros kai\\ *Ou)du/sseus",
+        "ti/s; lo/gos: s3 s1a #22a#5#1#2#",
+    ] {
+        assert_eq!(convert_with_map(input).0, convert(input));
+    }
+}
+#[test]
+fn convert_with_map_dangling_asterisk() {
+    for input in ["a * b", "*", "qeo/s *"] {
+        assert_eq!(convert_with_map(input).0, convert(input));
+    }
+    let (output, map) = convert_with_map("a * b");
+    assert_eq!(output, "α * β");
+    assert_eq!(map.to_greek(2..3), 3..4);
+}
+#[test]
+fn span_map() {
+    let input = "a)/ndra moi e)/nnepe";
+    let (output, map) = convert_with_map(input);
+    assert_eq!(&output[map.to_greek(8..11)], "μοι");
+    assert_eq!(&input[map.to_beta(0..4)], "a)/n");
+    assert_eq!(map.to_greek(1..2), 0..3);
+    assert_eq!(map.to_greek(100..101), output.len()..output.len());
+}
+#[test]
+fn elision() {
+    assert_eq!(convert("d' a)/ra"), "δ’ ἄρα");
+    assert_eq!(convert("ka)gw/"), "κἀγώ");
+    assert_eq!(convert("e)/fas' a)/ra"), "ἔφασ’ ἄρα");
+    let options = ConvertOptions {
+        elision: Elision::Koronis,
+        ..Default::default()
+    };
+    assert_eq!(convert_with("d' a)/ra", &options), "δ\u{1fbd} ἄρα");
+    assert_eq!(revert("δ\u{1fbd} ἄρα"), "d' a)/ra");
+    assert_eq!(revert("δ’ ἄρα"), "d' a)/ra");
+}
+#[test]
+fn dehyphenate() {
+    let input = "tou= lo/-\ngos, kai\\ a)/llos-\n  tis";
+    let mut options = ConvertOptions {
+        dehyphenate: true,
+        ..Default::default()
+    };
+    assert_eq!(convert_with(input, &options), "τοῦ λόγος,\nκαὶ ἄλλοστις");
+    options.hyphen_marker = Some('|');
+    assert_eq!(convert_with(input, &options), "τοῦ λό|γος,\nκαὶ ἄλλοσ|τις");
+    assert_eq!(convert("a)/llos-\ntis"), "ἄλλοσ-\nτις");
+}
+#[test]
+fn dehyphenate_line_ends() {
+    let mut options = ConvertOptions {
+        dehyphenate: true,
+        ..Default::default()
+    };
+    // The continuation is the whole line: its line break is kept, none is added
+    assert_eq!(convert_with("lo/-\ngos\nkai\\", &options), "λόγος\nκαὶ");
+    assert_eq!(
+        convert_with("a)/n-\r\nqrwpon\r\nkai\\", &options),
+        "ἄνθρωπον\r\nκαὶ"
+    );
+    assert_eq!(convert_with("lo/-\ngos", &options), "λόγος");
+    // A moved line break keeps the line ends of the text
+    assert_eq!(
+        convert_with("a)/n-\r\nqrwpon kai\\\r\n", &options),
+        "ἄνθρωπον\r\nκαὶ\r\n"
+    );
+    // A word broken across three lines
+    assert_eq!(convert_with("a)/n-\nqrw-\npos", &options), "ἄνθρωπος");
+    assert_eq!(
+        convert_with("o( a)/n-\nqrw-\npos kai\\\nlo/gos", &options),
+        "ὁ ἄνθρωπος\nκαὶ\nλόγος"
+    );
+    options.hyphen_marker = Some('|');
+    assert_eq!(convert_with("a)/n-\nqrw-\npos", &options), "ἄν|θρω|πος");
+}
+
+#[test]
+fn iota_adscript() {
+    let input = "*(/a|dhs tw=| *)w|dei/w|";
+    assert_eq!(convert(input), "ᾍδης τῷ ᾨδείῳ");
+    let mut options = ConvertOptions {
+        iota_adscript: IotaAdscript::Capitals,
+        ..Default::default()
+    };
+    assert_eq!(convert_with(input, &options), "Ἅιδης τῷ Ὠιδείῳ");
+    options.iota_adscript = IotaAdscript::Everywhere;
+    assert_eq!(convert_with(input, &options), "Ἅιδης τῶι Ὠιδείωι");
+    let options = ConvertOptions {
+        all_caps: true,
+        ..Default::default()
+    };
+    assert_eq!(convert_with("tw=| qew=|", &options), "ΤΩΙ ΘΕΩΙ");
+}
+#[test]
+fn revert_adscript() {
+    assert_eq!(revert("Ἅιδης τῶι Ὠιδείωι"), "*a(/|dhs tw=i *w)|dei/wi");
+    assert_eq!(revert("ᾍδης"), "*a(/|dhs");
+    assert_eq!(revert("ΤΩΙ"), "*t*w*i");
+    assert_eq!(revert("Αιγυπτος"), "*aiguptos");
+    assert_eq!(revert("αἰεί"), "ai)ei/");
+    let options = RevertOptions {
+        iota_adscript: true,
+    };
+    assert_eq!(
+        revert_with("Ἅιδης τῶι Ὠιδείωι ὠιδή", &options),
+        "*a(/|dhs tw=| *w)|dei/w| w)|dh/"
+    );
+    assert_eq!(revert_with("ωιʹ", &options), "wi#");
+    assert_eq!(revert_with("αἰεί", &options), "ai)ei/");
+}
+#[test]
+fn revert_plain_iota() {
+    assert_eq!(revert("πρωινός"), "prwino/s");
+    assert_eq!(revert("ηρωικός"), "hrwiko/s");
+    assert_eq!(revert("ΠΡΩΙΝΟΣ"), "*p*r*w*i*n*o*s");
+    let options = RevertOptions {
+        iota_adscript: true,
+    };
+    assert_eq!(revert_with("πρωινός", &options), "prwino/s");
+    assert_eq!(revert_with("ἡρωικός", &options), "h(rwiko/s");
+}
+#[test]
+fn keep_capitals() {
+    let options = ConvertOptions {
+        keep_capitals: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        convert_with("MH=NIN A)/EIDE QEA\\", &options),
+        "ΜΗΝΙΝ ΑΕΙΔΕ ΘΕΑ"
+    );
+    assert_eq!(convert_with("mh=nin", &options), "μῆνιν");
+    assert_eq!(convert_with("*mh=nin", &options), "Μῆνιν");
+}
+#[test]
+fn numerals_skip_markup() {
+    let options = ConvertOptions {
+        numerals: true,
+        ..Default::default()
+    };
+    assert_eq!(convert_with("[1 a 12", &options), "[1 α ιβʹ");
+}
+#[test]
+fn numerals_all_caps() {
+    let options = ConvertOptions {
+        numerals: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        convert_with("MHNIN 12 A)/EIDE", &options),
+        "μηνιν ιβʹ ἄειδε"
+    );
+    assert_eq!(convert_with("*MHNIN 12", &options), "Μηνιν ιβʹ");
+    let options = ConvertOptions {
+        numerals: true,
+        keep_capitals: true,
+        ..Default::default()
+    };
+    assert_eq!(convert_with("MHNIN 12", &options), "ΜΗΝΙΝ ΙΒʹ");
+}
@@ -0,0 +1,318 @@
+use crate::converter::convert;
+use crate::greek::{self, Letter, ACUTE, CIRCUMFLEX, DIAERESIS, GRAVE};
+use crate::monotonic::to_monotonic;
+use crate::validator::{validate, ValidationError};
+use unicode_normalization::UnicodeNormalization;
+
+/// A romanized text read back into Greek, produced by [from_elot] and
+/// [from_greeklish](crate::greeklish::from_greeklish).
+#[derive(Debug, Clone, PartialEq)]
+pub struct GreekText {
+    pub betacode: String,
+    pub greek: String,
+}
+
+/// Reads the Beta Code of the romanized letters starting at a position of a word,
+/// returning the number of Latin letters read.
+pub(crate) type Matcher = fn(&[char], usize) -> Option<(usize, &'static str)>;
+
+/// Characters kept as Beta Code punctuation between the romanized words.
+const PUNCTUATION: &str = " \r\n.,;:'-";
+
+/// Converts a romanized text into validated Beta Code and Unicode Greek, reading the
+/// letters of each word (as told by `is_letter`) with the given matcher.
+///
+/// Acute accents and diaereses are kept on the Greek letter produced by the romanized
+/// letters that carry them. Digits and other characters that are neither letters nor
+/// punctuation are copied through unchanged.
+pub(crate) fn romanized_to_greek(
+    input: &str,
+    matcher: Matcher,
+    is_letter: fn(&char) -> bool,
+) -> Result<GreekText, ValidationError> {
+    let chars: Vec<char> = input.nfd().collect();
+    // Runs of Beta Code, each followed by the text copied through after it
+    let mut pieces: Vec<(String, String)> = vec![(String::new(), String::new())];
+    let mut unknown = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if !pieces[pieces.len() - 1].1.is_empty()
+            && (is_letter(&c) || c == '?' || PUNCTUATION.contains(c))
+        {
+            pieces.push((String::new(), String::new()));
+        }
+        let last = pieces.len() - 1;
+        let (betacode, copied) = &mut pieces[last];
+        match c {
+            '?' => betacode.push(';'),
+            c if is_letter(&c) => {
+                // Reads the word without its marks
+                let mut letters = vec![];
+                let mut marks = vec![];
+                let mut j = i;
+                while j < chars.len() && (is_letter(&chars[j]) || greek::is_combining(chars[j])) {
+                    match greek::is_combining(chars[j]) {
+                        true => marks.push((letters.len() - 1, chars[j])),
+                        false => letters.push(chars[j]),
+                    }
+                    j += 1;
+                }
+                let lower: Vec<char> = letters.iter().map(|c| c.to_ascii_lowercase()).collect();
+                let mut k = 0;
+                while k < lower.len() {
+                    let (len, beta) = match matcher(&lower, k) {
+                        Some(unit) => unit,
+                        None => {
+                            unknown.push(letters[k]);
+                            (1, "")
+                        }
+                    };
+                    if letters[k].is_ascii_uppercase() {
+                        betacode.push('*');
+                    }
+                    betacode.push_str(beta);
+                    for (_, mark) in marks.iter().filter(|(m, _)| (k..k + len).contains(m)) {
+                        match *mark {
+                            DIAERESIS => betacode.push('+'),
+                            ACUTE | GRAVE | CIRCUMFLEX => betacode.push('/'),
+                            _ => (),
+                        }
+                    }
+                    k += len;
+                }
+                i = j;
+                continue;
+            }
+            c if greek::is_combining(c) && copied.is_empty() => (),
+            c if PUNCTUATION.contains(c) => betacode.push(c),
+            c => copied.push(c),
+        }
+        i += 1;
+    }
+    if !unknown.is_empty() {
+        unknown.dedup();
+        return Err(ValidationError::InvalidChars(unknown));
+    }
+    let mut output = GreekText {
+        betacode: String::new(),
+        greek: String::new(),
+    };
+    for (betacode, copied) in pieces {
+        validate(&betacode)?;
+        let copied: String = copied.nfc().collect();
+        output.greek.push_str(&convert(betacode.as_str()));
+        output.greek.push_str(&copied);
+        output.betacode.push_str(&betacode);
+        output.betacode.push_str(&copied);
+    }
+    Ok(output)
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiouy".contains(c)
+}
+
+/// Letters after which `av`, `ev` and `iv` stand for `αυ`, `ευ` and `ηυ` (voiced consonants),
+/// and `af`, `ef` and `if` for the same diphthongs (voiceless consonants).
+const VOICED: &str = "bgdzlmnrv";
+const VOICELESS: &str = "tckxpsf";
+
+/// ELOT 743 letters and digraphs with their Beta Code, longest first.
+const ELOT_UNITS: [(&str, &str); 28] = [
+    ("nch", "gx"),
+    ("th", "q"),
+    ("ch", "x"),
+    ("ps", "y"),
+    ("ou", "ou"),
+    ("ng", "gg"),
+    ("nx", "gc"),
+    ("mb", "mp"),
+    ("b", "mp"),
+    ("v", "b"),
+    ("x", "c"),
+    ("y", "u"),
+    ("a", "a"),
+    ("g", "g"),
+    ("d", "d"),
+    ("e", "e"),
+    ("z", "z"),
+    ("i", "i"),
+    ("k", "k"),
+    ("l", "l"),
+    ("m", "m"),
+    ("n", "n"),
+    ("o", "o"),
+    ("p", "p"),
+    ("r", "r"),
+    ("s", "s"),
+    ("t", "t"),
+    ("f", "f"),
+];
+
+fn elot_letter(word: &[char], i: usize) -> Option<(usize, &'static str)> {
+    let at = |s: &str| {
+        s.chars()
+            .enumerate()
+            .all(|(n, c)| word.get(i + n) == Some(&c))
+    };
+    let next = |n: usize| word.get(i + n).copied();
+    // αυ, ευ, ηυ before a consonant (or at the end of the word)
+    if let (Some(first), Some(v)) = (next(0), next(1)) {
+        let before = next(2);
+        let diphthong = match (v, before) {
+            ('v', Some(c)) => VOICED.contains(c),
+            ('f', Some(c)) => VOICELESS.contains(c),
+            ('f', None) => true,
+            _ => false,
+        };
+        if diphthong && "aei".contains(first) && !word.get(i + 2).is_some_and(|c| is_vowel(*c)) {
+            let beta = match first {
+                'a' => "au",
+                'e' => "eu",
+                _ => "hu",
+            };
+            return Some((2, beta));
+        }
+    }
+    ELOT_UNITS
+        .iter()
+        .find(|(latin, _)| at(latin))
+        .map(|(latin, beta)| (latin.len(), *beta))
+}
+
+/// Reads an ELOT 743 romanization back into Beta Code and Unicode Greek.
+///
+/// The romanization is not reversible: `i` is read as `ι`, `o` as `ο`, `b` and `mb` as `μπ`
+/// and `av`, `ev` before a vowel as `αβ`, `εβ`. Digits and other non-Latin characters
+/// are kept as they are, and letters outside ELOT 743 produce a [ValidationError::InvalidChars].
+///
+/// ```
+/// use betacode::elot::from_elot;
+/// let result = from_elot("Kaliméra").unwrap();
+/// assert_eq!(result.greek, "Καλιμέρα");
+/// assert_eq!(from_elot("ávrio").unwrap().greek, "αύριο");
+/// assert_eq!(from_elot("theoría").unwrap().betacode, "qeori/a");
+/// ```
+pub fn from_elot<T: Into<String>>(input: T) -> Result<GreekText, ValidationError> {
+    romanized_to_greek(&input.into(), elot_letter, char::is_ascii_alphabetic)
+}
+
+fn elot_vowel(c: char) -> &'static str {
+    match c {
+        'α' => "a",
+        'ε' => "e",
+        'η' | 'ι' => "i",
+        'ο' | 'ω' => "o",
+        _ => "y",
+    }
+}
+
+fn elot_word(word: &[Letter]) -> String {
+    let mut output = String::new();
+    let mut i = 0;
+    while i < word.len() {
+        let c = word[i].lower();
+        let next = word.get(i + 1);
+        let pair: String = [Some(c), next.map(|l| l.lower())]
+            .iter()
+            .flatten()
+            .collect();
+        let (latin, len) = match pair.as_str() {
+            "αυ" | "ευ" | "ηυ" if !next.is_some_and(|l| l.has(DIAERESIS)) => {
+                let voiceless = match word.get(i + 2) {
+                    Some(l) => "θκξπστφχψ".contains(l.lower()),
+                    None => true,
+                };
+                let v = match voiceless {
+                    true => "f",
+                    false => "v",
+                };
+                (format!("{}{}", elot_vowel(c), v), 2)
+            }
+            "ου" if !next.is_some_and(|l| l.has(DIAERESIS)) => ("ou".to_string(), 2),
+            "γγ" => ("ng".to_string(), 2),
+            "γξ" => ("nx".to_string(), 2),
+            "γχ" => ("nch".to_string(), 2),
+            "μπ" if i == 0 || i + 2 == word.len() => ("b".to_string(), 2),
+            "μπ" => ("mb".to_string(), 2),
+            _ => {
+                let latin = match c {
+                    'α' | 'ε' | 'η' | 'ι' | 'ο' | 'υ' | 'ω' => elot_vowel(c),
+                    'β' => "v",
+                    'γ' => "g",
+                    'δ' => "d",
+                    'ζ' => "z",
+                    'θ' => "th",
+                    'κ' => "k",
+                    'λ' => "l",
+                    'μ' => "m",
+                    'ν' => "n",
+                    'ξ' => "x",
+                    'π' => "p",
+                    'ρ' => "r",
+                    'σ' => "s",
+                    'τ' => "t",
+                    'φ' => "f",
+                    'χ' => "ch",
+                    'ψ' => "ps",
+                    _ => "",
+                };
+                (latin.to_string(), 1)
+            }
+        };
+        // Marks go on the last Latin vowel of the unit, the diaeresis before the accent
+        let mut latin = latin;
+        let unit = &word[i..i + len];
+        if let Some(p) = latin.rfind(is_vowel) {
+            let mut marks = String::new();
+            if unit.iter().any(|l| l.has(DIAERESIS)) {
+                marks.push(DIAERESIS);
+            }
+            if unit.iter().any(|l| l.accent().is_some()) {
+                marks.push(ACUTE);
+            }
+            latin.insert_str(p + 1, &marks);
+        }
+        if word[i].is_upper() {
+            latin = crate::transliteration::capitalize(&latin);
+        }
+        output.push_str(&latin);
+        i += len;
+    }
+    output
+}
+
+/// Romanizes a Beta Code or Unicode text following ELOT 743, the Greek standard
+/// for Modern Greek, after reducing it to the monotonic system.
+///
+/// ```
+/// use betacode::elot::to_elot;
+/// assert_eq!(to_elot("καλημέρα"), "kaliméra");
+/// assert_eq!(to_elot("Θεσσαλονίκη"), "Thessaloníki");
+/// assert_eq!(to_elot("αὔριο μπαίνω"), "ávrio baíno");
+/// ```
+pub fn to_elot<T: Into<String>>(input: T) -> String {
+    let letters = greek::letters(to_monotonic(input));
+    let mut output = String::new();
+    let mut i = 0;
+    while i < letters.len() {
+        if !letters[i].is_greek() {
+            match letters[i].base {
+                ';' => output.push('?'),
+                '·' => output.push(';'),
+                c => output.push(c),
+            }
+            i += 1;
+            continue;
+        }
+        let end = (i..letters.len())
+            .find(|j| !letters[*j].is_greek())
+            .unwrap_or(letters.len());
+        output.push_str(&elot_word(&letters[i..end]));
+        i = end;
+    }
+    output.nfc().collect()
+}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,43 @@
+use super::*;
+#[test]
+fn romanize() {
+    assert_eq!(to_elot("Ευαγγέλιο"), "Evangélio");
+    assert_eq!(to_elot("ευχαριστώ"), "efcharistó");
+    assert_eq!(to_elot("*)aqhnai=oi"), "Athinaíoi");
+    assert_eq!(to_elot("κοροϊδεύω"), "koroïdévo");
+    assert_eq!(to_elot("πού είσαι;"), "poú eísai?");
+}
+#[test]
+fn read_back() {
+    assert_eq!(
+        from_elot("efcharistó").unwrap().greek,
+        "ευχαριστώ".replace('ώ', "ό")
+    );
+    assert_eq!(from_elot("Evangélio").unwrap().betacode, "*ebagge/lio");
+    assert_eq!(from_elot("Evgenía").unwrap().betacode, "*eugeni/a");
+    assert_eq!(from_elot("proïón").unwrap().greek, "προϊόν");
+    assert_eq!(from_elot("poú eísai?").unwrap().greek, "πού είσαι;");
+}
+#[test]
+fn invalid_letters() {
+    match from_elot("hello") {
+        Err(ValidationError::InvalidChars(c)) => assert_eq!(c, vec!['h']),
+        _ => panic!("h is not an ELOT 743 letter"),
+    }
+}
+#[test]
+fn numbers_and_other_characters() {
+    let result = from_elot("Odós 25").unwrap();
+    assert_eq!(result.greek, "Οδός 25");
+    assert_eq!(result.betacode, "*odo/s 25");
+    assert_eq!(from_elot("(1990) théma!").unwrap().greek, "(1990) θέμα!");
+    assert_eq!(to_elot("Οδός 25"), "Odós 25");
+}
+#[test]
+fn mb_within_words() {
+    assert_eq!(to_elot("Ολυμπία"), "Olymbía");
+    assert_eq!(to_elot("λάμπα"), "lámba");
+    assert_eq!(to_elot("μπαμπάς"), "bambás");
+    assert_eq!(from_elot("lámba").unwrap().greek, "λάμπα");
+    assert_eq!(from_elot("bambás").unwrap().greek, "μπαμπάς");
+}
@@ -0,0 +1,228 @@
+use crate::greek::{
+    self, ACUTE, BREVE, CIRCUMFLEX, DIAERESIS, GRAVE, IOTA_SUBSCRIPT, MACRON, ROUGH, SMOOTH,
+};
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+lazy_static! {
+    static ref RE_ITACISM: Regex = Regex::new(r"ει|οι|υι|ου|αυ|ευ|ηυ|η|υ|ω|ϊ|ϋ").unwrap();
+}
+lazy_static! {
+    static ref RE_XYN: Regex = Regex::new(r"\bξυ([νμγ])").unwrap();
+}
+
+/// Named folding profiles producing search keys with [search_key].
+///
+/// - [FoldingProfile::Strict]: only the encoding and the Unicode normalization are folded.
+/// - [FoldingProfile::AccentInsensitive]: as [fold], ignoring case, diacritics and sigma forms.
+/// - [FoldingProfile::Itacistic]: also merges `ι`, `η`, `υ`, `ει`, `οι` (and `υι`) into `ι`
+///   and `ω` into `ο`. Vowels separated by a diaeresis (`προϊόν`) are not digraphs.
+/// - [FoldingProfile::Orthographic]: also merges `ττ` into `σσ` and the prefix `ξυν-`
+///   (`ξυμ-`, `ξυγ-`) into `συν-`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FoldingProfile {
+    Strict,
+    AccentInsensitive,
+    Itacistic,
+    Orthographic,
+}
+
+/// Selects which classes of diacritics [strip_diacritics] removes.
+///
+/// The default removes all of them.
+#[derive(Debug, Clone)]
+pub struct StripOptions {
+    /// Acute, grave and circumflex (`/`, `\`, `=`).
+    pub accents: bool,
+    /// Smooth and rough breathings (`)`, `(`).
+    pub breathings: bool,
+    /// Diaeresis (`+`).
+    pub diaeresis: bool,
+    /// Iota subscript (`|`).
+    pub iota_subscript: bool,
+    /// Macron and breve (`%26`, `%27`).
+    pub quantity: bool,
+}
+
+impl Default for StripOptions {
+    fn default() -> Self {
+        StripOptions {
+            accents: true,
+            breathings: true,
+            diaeresis: true,
+            iota_subscript: true,
+            quantity: true,
+        }
+    }
+}
+
+impl StripOptions {
+    fn beta_codes(&self) -> Vec<&'static str> {
+        let mut codes = vec![];
+        if self.accents {
+            codes.extend(["/", "\\", "="]);
+        }
+        if self.breathings {
+            codes.extend([")", "("]);
+        }
+        if self.diaeresis {
+            codes.push("+");
+        }
+        if self.iota_subscript {
+            codes.push("|");
+        }
+        if self.quantity {
+            codes.extend(["%26", "%27"]);
+        }
+        codes
+    }
+    fn marks(&self) -> Vec<char> {
+        let mut marks = vec![];
+        if self.accents {
+            marks.extend([ACUTE, GRAVE, CIRCUMFLEX]);
+        }
+        if self.breathings {
+            marks.extend([SMOOTH, ROUGH]);
+        }
+        if self.diaeresis {
+            marks.push(DIAERESIS);
+        }
+        if self.iota_subscript {
+            marks.push(IOTA_SUBSCRIPT);
+        }
+        if self.quantity {
+            marks.extend([MACRON, BREVE]);
+        }
+        marks
+    }
+}
+
+/// Removes the selected diacritics from a Beta Code or Unicode text,
+/// keeping the encoding of the input.
+///
+/// ```
+/// use betacode::folding::{strip_diacritics, StripOptions};
+/// let options = StripOptions::default();
+/// assert_eq!(strip_diacritics("lo/gos", &options), "logos");
+/// assert_eq!(strip_diacritics("λόγος", &options), "λογος");
+/// ```
+///
+/// Each class of diacritics can be kept:
+///
+/// ```
+/// use betacode::folding::{strip_diacritics, StripOptions};
+/// let options = StripOptions {
+///     breathings: false,
+///     ..Default::default()
+/// };
+/// assert_eq!(strip_diacritics("ᾧ", &options), "ὡ");
+/// assert_eq!(strip_diacritics("w(=|", &options), "w(");
+/// ```
+pub fn strip_diacritics<T: Into<String>>(input: T, options: &StripOptions) -> String {
+    let input: String = input.into();
+    match greek::is_betacode(&input) {
+        true => {
+            let mut output = input;
+            options.beta_codes().iter().for_each(|c| {
+                output = output.replace(c, "");
+            });
+            output
+        }
+        false => {
+            let marks = options.marks();
+            let mut letters = greek::letters(input);
+            letters
+                .iter_mut()
+                .for_each(|l| l.marks.retain(|m| !marks.contains(m)));
+            greek::render(&letters)
+        }
+    }
+}
+
+/// Folds a Beta Code or Unicode text into lowercase Unicode Greek without diacritics,
+/// with final and lunate sigmas written as `σ`.
+///
+/// ```
+/// assert_eq!(betacode::folding::fold("*(/omhros"), "ομηροσ");
+/// ```
+pub fn fold<T: Into<String>>(input: T) -> String {
+    let stripped = strip_diacritics(greek::to_unicode(input), &StripOptions::default());
+    greek::letters(stripped).iter().map(|l| l.lower()).collect()
+}
+
+/// As [fold], but writes `ι` and `υ` with a diaeresis as `ϊ` and `ϋ`, which
+/// keeps them out of the digraphs of the itacistic profiles.
+fn fold_keeping_diaeresis<T: Into<String>>(input: T) -> String {
+    greek::letters(greek::to_unicode(input))
+        .iter()
+        .map(|l| match (l.lower(), l.has(DIAERESIS)) {
+            ('ι', true) => 'ϊ',
+            ('υ', true) => 'ϋ',
+            (c, _) => c,
+        })
+        .collect()
+}
+
+/// Compares two words or texts regardless of encoding, case and diacritics.
+///
+/// ```
+/// assert!(betacode::folding::eq_folded("lo/gos", "λόγος"));
+/// assert!(betacode::folding::eq_folded("Λόγος", "λογος"));
+/// assert!(!betacode::folding::eq_folded("λόγος", "λόγου"));
+/// ```
+pub fn eq_folded<T: Into<String>, U: Into<String>>(a: T, b: U) -> bool {
+    fold(a) == fold(b)
+}
+/// Maps a Beta Code or Unicode word (or text) to a Unicode search key under the given profile.
+///
+/// Two words match under a profile when their keys are equal.
+///
+/// ```
+/// use betacode::folding::{search_key, FoldingProfile};
+/// assert_eq!(search_key("lo/gos", FoldingProfile::Strict), "λόγος");
+/// assert_eq!(search_key("λόγος", FoldingProfile::AccentInsensitive), "λογοσ");
+/// assert_eq!(search_key("λώγος", FoldingProfile::Itacistic), "λογοσ");
+/// assert_eq!(search_key("ξυμμαχία", FoldingProfile::Orthographic), "σιμμαχια");
+/// ```
+pub fn search_key<T: Into<String>>(input: T, profile: FoldingProfile) -> String {
+    if profile == FoldingProfile::Strict {
+        return greek::to_unicode(input);
+    }
+    let itacistic = profile == FoldingProfile::Itacistic || profile == FoldingProfile::Orthographic;
+    let mut output = match itacistic {
+        true => fold_keeping_diaeresis(input),
+        false => fold(input),
+    };
+    if profile == FoldingProfile::Orthographic {
+        output = output.replace("ττ", "σσ");
+        output = RE_XYN.replace_all(&output, "συ$1").to_string();
+    }
+    if itacistic {
+        output = RE_ITACISM
+            .replace_all(&output, |c: &Captures| match &c[0] {
+                "ει" | "οι" | "υι" | "η" | "υ" | "ϊ" | "ϋ" => "ι".to_string(),
+                "ω" => "ο".to_string(),
+                other => other.to_string(),
+            })
+            .to_string();
+    }
+    output
+}
+
+/// Compares two words or texts under the given folding profile.
+///
+/// ```
+/// use betacode::folding::{eq_folded_with, FoldingProfile};
+/// assert!(eq_folded_with("λόγος", "λώγος", FoldingProfile::Itacistic));
+/// assert!(!eq_folded_with("λόγος", "λώγος", FoldingProfile::AccentInsensitive));
+/// assert!(eq_folded_with("qa/lassa", "θάλαττα", FoldingProfile::Orthographic));
+/// ```
+pub fn eq_folded_with<T: Into<String>, U: Into<String>>(
+    a: T,
+    b: U,
+    profile: FoldingProfile,
+) -> bool {
+    search_key(a, profile) == search_key(b, profile)
+}
+#[cfg(test)]
+mod test;
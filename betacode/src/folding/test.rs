@@ -0,0 +1,80 @@
+use super::*;
+#[test]
+fn strip_betacode() {
+    let options = StripOptions::default();
+    assert_eq!(strip_diacritics("lo/gos", &options), "logos");
+    assert_eq!(
+        strip_diacritics("*)a/nqrwpos *phlhi+a/dew a%26", &options),
+        "*anqrwpos *phlhiadew a"
+    );
+    let options = StripOptions {
+        accents: false,
+        ..Default::default()
+    };
+    assert_eq!(strip_diacritics("*)a/nqrwpos", &options), "*a/nqrwpos");
+}
+#[test]
+fn strip_unicode() {
+    let options = StripOptions::default();
+    assert_eq!(
+        strip_diacritics("μῆνιν ἄειδε θεὰ", &options),
+        "μηνιν αειδε θεα"
+    );
+    assert_eq!(strip_diacritics("Πηληϊάδεω ᾠδῇ", &options), "Πηληιαδεω ωδη");
+    let options = StripOptions {
+        diaeresis: false,
+        iota_subscript: false,
+        ..Default::default()
+    };
+    assert_eq!(strip_diacritics("Πηληϊάδεω ᾠδῇ", &options), "Πηληϊαδεω ῳδῃ");
+}
+#[test]
+fn folded_comparison() {
+    assert!(eq_folded("*)a/nqrwpos", "ἄνθρωπος"));
+    assert!(eq_folded("ΛΟΓΟΣ", "λόγος"));
+    assert!(eq_folded("λογοσ", "λόγος"));
+    assert!(!eq_folded("λόγος", "λώγος"));
+}
+#[test]
+fn search_profiles() {
+    assert_eq!(search_key("λόγος", FoldingProfile::Strict), "λόγος");
+    assert!(!eq_folded_with("λογοσ", "λόγος", FoldingProfile::Strict));
+    assert!(eq_folded_with(
+        "λογοσ",
+        "λόγος",
+        FoldingProfile::AccentInsensitive
+    ));
+    assert_eq!(search_key("οἰκεῖν", FoldingProfile::Itacistic), "ικιν");
+    assert_eq!(search_key("ἡμεῖς", FoldingProfile::Itacistic), "ιμισ");
+    assert_eq!(search_key("οὐρανός", FoldingProfile::Itacistic), "ουρανοσ");
+    assert_eq!(search_key("εὐθύς", FoldingProfile::Itacistic), "ευθισ");
+    assert!(eq_folded_with("ὑμεῖς", "ἡμῖς", FoldingProfile::Itacistic));
+    assert!(eq_folded_with(
+        "ξυνοικέω",
+        "συνοικέω",
+        FoldingProfile::Orthographic
+    ));
+    assert!(!eq_folded_with(
+        "ξυνοικέω",
+        "συνοικέω",
+        FoldingProfile::Itacistic
+    ));
+}
+#[test]
+fn itacism_diaeresis() {
+    assert_eq!(search_key("προϊόν", FoldingProfile::Itacistic), "προιον");
+    assert_eq!(
+        search_key("proi+o/n", FoldingProfile::Orthographic),
+        "προιον"
+    );
+    assert!(!eq_folded_with(
+        "προϊόν",
+        "πριον",
+        FoldingProfile::Itacistic
+    ));
+    assert_eq!(
+        search_key("Πηληϊάδεω", FoldingProfile::Itacistic),
+        "πιλιιαδεο"
+    );
+    assert_eq!(search_key("ἀϋτή", FoldingProfile::Itacistic), "αιτι");
+}
@@ -0,0 +1,172 @@
+use crate::greek::{self, Letter};
+
+/// Pairs of letters commonly confused in OCR'd and hand-keyed texts, folded.
+const CONFUSABLES: [(char, char); 2] = [('ο', 'ω'), ('ε', 'η')];
+
+/// Costs of the edits counted by [distance_with].
+///
+/// The default makes diacritic differences cheap (`0.1`), the [CONFUSABLES] and
+/// `ι`/`ει` medium (`0.5`) and the other letter differences expensive (`1.0`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weights {
+    /// Same letter with different diacritics or case.
+    pub diacritic: f32,
+    /// Commonly confused letters (`ο`/`ω`, `ε`/`η`, `ι`/`ει`).
+    pub confusable: f32,
+    /// Any other substitution, insertion or deletion.
+    pub letter: f32,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights {
+            diacritic: 0.1,
+            confusable: 0.5,
+            letter: 1.0,
+        }
+    }
+}
+
+/// A word found by [fuzzy_find], with its index in the word list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyMatch {
+    pub index: usize,
+    pub word: String,
+    pub distance: f32,
+}
+
+fn same_marks(a: &Letter, b: &Letter) -> bool {
+    let mut a_marks = a.marks.clone();
+    let mut b_marks = b.marks.clone();
+    a_marks.sort_unstable();
+    b_marks.sort_unstable();
+    a_marks == b_marks && a.is_upper() == b.is_upper()
+}
+
+fn substitution(a: &Letter, b: &Letter, weights: &Weights) -> f32 {
+    let (x, y) = (a.lower(), b.lower());
+    let marks = match same_marks(a, b) {
+        true => 0.0,
+        false => weights.diacritic,
+    };
+    if x == y {
+        return marks;
+    }
+    match CONFUSABLES.contains(&(x, y)) || CONFUSABLES.contains(&(y, x)) {
+        true => weights.confusable + marks,
+        false => weights.letter,
+    }
+}
+
+/// Whether the letters before `i` end with `ει` and the letter before `j` is an `ι`.
+fn is_ei_for_i(a: &[Letter], i: usize, b: &[Letter], j: usize) -> bool {
+    i >= 2
+        && j >= 1
+        && a[i - 2].lower() == 'ε'
+        && a[i - 1].lower() == 'ι'
+        && b[j - 1].lower() == 'ι'
+}
+
+/// Weighted edit distance between two Beta Code or Unicode words, with the default [Weights].
+///
+/// ```
+/// use betacode::fuzzy::distance;
+/// assert_eq!(distance("lo/gos", "λόγος"), 0.0);
+/// assert!(distance("λόγος", "λογος") < distance("λόγος", "λώγος"));
+/// assert!(distance("λόγος", "λώγος") < distance("λόγος", "λόπος"));
+/// ```
+pub fn distance<T: Into<String>, U: Into<String>>(a: T, b: U) -> f32 {
+    distance_with(a, b, &Weights::default())
+}
+
+/// Weighted edit distance between two Beta Code or Unicode words.
+///
+/// Both words are read through the converter, so the encodings can be mixed.
+/// Substituting, inserting or deleting a letter costs [Weights::letter], except for
+/// diacritic or case differences ([Weights::diacritic]) and for the confusable
+/// letters, including `ει` written for `ι` and the reverse ([Weights::confusable],
+/// plus [Weights::diacritic] if their diacritics differ).
+///
+/// ```
+/// use betacode::fuzzy::{distance_with, Weights};
+/// let weights = Weights {
+///     diacritic: 0.0,
+///     ..Default::default()
+/// };
+/// assert_eq!(distance_with("ἄνθρωπος", "ανθρωπος", &weights), 0.0);
+/// assert_eq!(distance_with("πολιτεία", "πολειτεια", &weights), 0.5);
+/// ```
+pub fn distance_with<T: Into<String>, U: Into<String>>(a: T, b: U, weights: &Weights) -> f32 {
+    let a = greek::letters(greek::to_unicode(a));
+    let b = greek::letters(greek::to_unicode(b));
+    letters_distance(&a, &b, weights, f32::INFINITY)
+}
+
+/// [distance_with] on already segmented words, giving up with infinity as
+/// soon as the distance is sure to exceed `bound`.
+pub(crate) fn letters_distance(a: &[Letter], b: &[Letter], weights: &Weights, bound: f32) -> f32 {
+    let mut costs = vec![vec![0.0f32; b.len() + 1]; a.len() + 1];
+    for i in 0..=a.len() {
+        for j in 0..=b.len() {
+            if i == 0 || j == 0 {
+                costs[i][j] = (i + j) as f32 * weights.letter;
+                continue;
+            }
+            let mut cost = (costs[i - 1][j - 1] + substitution(&a[i - 1], &b[j - 1], weights))
+                .min(costs[i - 1][j] + weights.letter)
+                .min(costs[i][j - 1] + weights.letter);
+            if is_ei_for_i(a, i, b, j) {
+                let iota = substitution(&a[i - 1], &b[j - 1], weights);
+                cost = cost.min(costs[i - 2][j - 1] + weights.confusable + iota);
+            }
+            if is_ei_for_i(b, j, a, i) {
+                let iota = substitution(&a[i - 1], &b[j - 1], weights);
+                cost = cost.min(costs[i - 1][j - 2] + weights.confusable + iota);
+            }
+            costs[i][j] = cost;
+        }
+        // A row only depends on the two rows above it.
+        let above = match i {
+            0 => f32::INFINITY,
+            _ => costs[i - 1].iter().copied().fold(f32::INFINITY, f32::min),
+        };
+        let row = costs[i].iter().copied().fold(f32::INFINITY, f32::min);
+        if above > bound && row > bound {
+            return f32::INFINITY;
+        }
+    }
+    costs[a.len()][b.len()]
+}
+
+/// Finds the words of a list within a [distance] of a Beta Code or Unicode word,
+/// closest first.
+///
+/// ```
+/// use betacode::fuzzy::fuzzy_find;
+/// let words = ["λόγος", "λόγου", "ἔργον", "λώγος"];
+/// let found = fuzzy_find("lo/gos", &words, 0.5);
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(found[0].word, "λόγος");
+/// assert_eq!(found[1].index, 3);
+/// ```
+pub fn fuzzy_find<T: Into<String>, U: AsRef<str>>(
+    word: T,
+    words: &[U],
+    max_distance: f32,
+) -> Vec<FuzzyMatch> {
+    let word: String = word.into();
+    let mut output: Vec<FuzzyMatch> = words
+        .iter()
+        .enumerate()
+        .map(|(index, w)| FuzzyMatch {
+            index,
+            word: w.as_ref().to_string(),
+            distance: distance(word.as_str(), w.as_ref()),
+        })
+        .filter(|m| m.distance <= max_distance)
+        .collect();
+    output.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+    output
+}
+#[cfg(test)]
+mod test;
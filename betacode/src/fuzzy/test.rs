@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn weighted_costs() {
+    assert_eq!(distance("λόγος", "λόγος"), 0.0);
+    assert_eq!(distance("*)a/nqrwpos", "Ἄνθρωπος"), 0.0);
+    assert_eq!(distance("λόγος", "λογος"), 0.1);
+    assert_eq!(distance("Λόγος", "λόγος"), 0.1);
+    assert_eq!(distance("λόγος", "λόγοσ"), 0.0);
+    assert_eq!(distance("λόγος", "λώγος"), 0.5);
+    assert_eq!(distance("μένος", "μήνος"), 0.5);
+    assert_eq!(distance("μένος", "μηνος"), 0.6);
+    assert_eq!(distance("λόγος", "λόπος"), 1.0);
+    assert_eq!(distance("λόγος", "λόγους"), 1.0);
+}
+#[test]
+fn ei_for_i() {
+    assert_eq!(distance("πολιτεία", "πολειτεία"), 0.5);
+    assert_eq!(distance("πολειτεία", "πολιτεία"), 0.5);
+    assert_eq!(distance("poli/ths", "πολείτης"), 0.5);
+    assert_eq!(distance("poli/ths", "πολειτης"), 0.6);
+}
+#[test]
+fn find() {
+    let words = vec!["θεός", "θεοῦ", "θεώς", "θέα", "ὁδός"];
+    let found = fuzzy_find("qeo/s", &words, 1.5);
+    let indices: Vec<usize> = found.iter().map(|m| m.index).collect();
+    assert_eq!(indices, vec![0, 2, 1]);
+    assert!(fuzzy_find("qeo/s", &words, 0.0).len() == 1);
+}
+#[test]
+fn bounded() {
+    let weights = Weights::default();
+    let a = greek::letters("πολιτεία");
+    let b = greek::letters("πολειτεια");
+    assert_eq!(letters_distance(&a, &b, &weights, 2.0), 0.6);
+    assert_eq!(letters_distance(&a, &b, &weights, 0.5), f32::INFINITY);
+    let c = greek::letters("ἄνθρωπος");
+    assert_eq!(letters_distance(&a, &c, &weights, 2.0), f32::INFINITY);
+}
@@ -0,0 +1,164 @@
+use crate::accentuation::Quantity;
+use crate::converter::{compose_unicode, convert, decompose_unicode};
+
+pub(crate) const SMOOTH: char = '\u{0313}';
+pub(crate) const ROUGH: char = '\u{0314}';
+pub(crate) const ACUTE: char = '\u{0301}';
+pub(crate) const GRAVE: char = '\u{0300}';
+pub(crate) const CIRCUMFLEX: char = '\u{0342}';
+pub(crate) const DIAERESIS: char = '\u{0308}';
+pub(crate) const IOTA_SUBSCRIPT: char = '\u{0345}';
+pub(crate) const MACRON: char = '\u{0304}';
+pub(crate) const BREVE: char = '\u{0306}';
+
+const DIPHTHONGS: [&str; 9] = ["αι", "ει", "οι", "υι", "αυ", "ευ", "ου", "ηυ", "ωυ"];
+
+/// A base character together with the combining marks that follow it in
+/// decomposed (NFKD) Unicode.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Letter {
+    pub base: char,
+    pub marks: Vec<char>,
+}
+
+impl Letter {
+    /// Lowercase base character, with final and lunate sigmas folded into σ.
+    pub fn lower(&self) -> char {
+        match self.base.to_lowercase().next().unwrap_or(self.base) {
+            'ς' | 'ϲ' => 'σ',
+            c => c,
+        }
+    }
+    pub fn is_greek(&self) -> bool {
+        is_greek_char(self.base)
+    }
+    pub fn is_vowel(&self) -> bool {
+        "αεηιουω".contains(self.lower())
+    }
+    pub fn is_upper(&self) -> bool {
+        self.base.is_uppercase()
+    }
+    pub fn has(&self, mark: char) -> bool {
+        self.marks.contains(&mark)
+    }
+    pub fn accent(&self) -> Option<char> {
+        self.marks
+            .iter()
+            .find(|m| [ACUTE, GRAVE, CIRCUMFLEX].contains(m))
+            .copied()
+    }
+    pub fn breathing(&self) -> Option<char> {
+        self.marks
+            .iter()
+            .find(|m| [SMOOTH, ROUGH].contains(m))
+            .copied()
+    }
+}
+
+pub(crate) fn is_greek_char(c: char) -> bool {
+    matches!(c, '\u{0370}'..='\u{03ff}' | '\u{1f00}'..='\u{1fff}') && c.is_alphabetic()
+}
+
+/// Whether the input should be read as Beta Code rather than Unicode Greek.
+pub(crate) fn is_betacode(input: &str) -> bool {
+    input.is_ascii()
+}
+
+/// Brings Beta Code or Unicode input to composed Unicode Greek.
+pub(crate) fn to_unicode<T: Into<String>>(input: T) -> String {
+    let input: String = input.into();
+    match is_betacode(&input) {
+        true => convert(input),
+        false => compose_unicode(input),
+    }
+}
+
+/// Splits the input into letters, attaching combining marks to their base.
+pub(crate) fn letters<T: Into<String>>(input: T) -> Vec<Letter> {
+    let mut output: Vec<Letter> = Vec::new();
+    for c in decompose_unicode(input).chars() {
+        match (is_combining(c), output.last_mut()) {
+            (true, Some(letter)) => letter.marks.push(c),
+            _ => output.push(Letter {
+                base: c,
+                marks: vec![],
+            }),
+        }
+    }
+    output
+}
+
+pub(crate) fn is_combining(c: char) -> bool {
+    matches!(c, '\u{0300}'..='\u{036f}')
+}
+
+/// Renders letters back into composed Unicode.
+pub(crate) fn render(letters: &[Letter]) -> String {
+    let mut output = String::new();
+    for letter in letters {
+        output.push(letter.base);
+        letter.marks.iter().for_each(|m| output.push(*m));
+    }
+    compose_unicode(output)
+}
+
+/// Splits the letters of a word into vowel nuclei (single vowels or diphthongs),
+/// returning the index ranges of each nucleus.
+pub(crate) fn nuclei(word: &[Letter]) -> Vec<std::ops::Range<usize>> {
+    let mut output = vec![];
+    let mut i = 0;
+    while i < word.len() {
+        if !word[i].is_vowel() {
+            i += 1;
+            continue;
+        }
+        if i + 1 < word.len() && is_diphthong(&word[i], &word[i + 1]) {
+            output.push(i..i + 2);
+            i += 2;
+        } else {
+            output.push(i..i + 1);
+            i += 1;
+        }
+    }
+    output
+}
+
+fn is_diphthong(first: &Letter, second: &Letter) -> bool {
+    let pair: String = [first.lower(), second.lower()].iter().collect();
+    DIPHTHONGS.contains(&pair.as_str())
+        && !second.has(DIAERESIS)
+        && first.accent().is_none()
+        && first.breathing().is_none()
+        && !first.has(IOTA_SUBSCRIPT)
+        && !first.has(MACRON)
+}
+
+/// Natural quantity of a nucleus.
+///
+/// With `for_accent`, word-final -αι and -οι count as short, as they do for
+/// the placement of the accent.
+pub(crate) fn quantity(
+    word: &[Letter],
+    nucleus: &std::ops::Range<usize>,
+    for_accent: bool,
+) -> Quantity {
+    let letters = &word[nucleus.clone()];
+    if letters.len() > 1 {
+        let pair: String = letters.iter().map(|l| l.lower()).collect();
+        let is_final = !word[nucleus.end..].iter().any(|l| l.is_greek());
+        if for_accent && is_final && (pair == "αι" || pair == "οι") {
+            return Quantity::Short;
+        }
+        return Quantity::Long;
+    }
+    let letter = &letters[0];
+    match letter.lower() {
+        'η' | 'ω' => Quantity::Long,
+        'ε' | 'ο' => Quantity::Short,
+        _ if letter.has(MACRON) || letter.has(CIRCUMFLEX) || letter.has(IOTA_SUBSCRIPT) => {
+            Quantity::Long
+        }
+        _ if letter.has(BREVE) => Quantity::Short,
+        _ => Quantity::Undetermined,
+    }
+}
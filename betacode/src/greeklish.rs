@@ -0,0 +1,68 @@
+use crate::elot::{romanized_to_greek, GreekText};
+use crate::validator::ValidationError;
+
+/// Greeklish spellings with their Beta Code, longest first. Digits stand for the
+/// letters they resemble (`8` for `θ`, `3` for `ξ`).
+const GREEKLISH_UNITS: [(&str, &str); 31] = [
+    ("th", "q"),
+    ("ch", "x"),
+    ("ks", "c"),
+    ("ps", "y"),
+    ("ou", "ou"),
+    ("8", "q"),
+    ("3", "c"),
+    ("x", "x"),
+    ("w", "w"),
+    ("h", "h"),
+    ("u", "u"),
+    ("y", "u"),
+    ("b", "b"),
+    ("v", "b"),
+    ("c", "k"),
+    ("a", "a"),
+    ("g", "g"),
+    ("d", "d"),
+    ("e", "e"),
+    ("z", "z"),
+    ("i", "i"),
+    ("k", "k"),
+    ("l", "l"),
+    ("m", "m"),
+    ("n", "n"),
+    ("o", "o"),
+    ("p", "p"),
+    ("r", "r"),
+    ("s", "s"),
+    ("t", "t"),
+    ("f", "f"),
+];
+
+fn greeklish_letter(word: &[char], i: usize) -> Option<(usize, &'static str)> {
+    GREEKLISH_UNITS
+        .iter()
+        .find(|(latin, _)| {
+            latin
+                .chars()
+                .enumerate()
+                .all(|(n, c)| word.get(i + n) == Some(&c))
+        })
+        .map(|(latin, beta)| (latin.len(), *beta))
+}
+
+/// Reads informal Greeklish back into Beta Code and Unicode Greek.
+///
+/// Both the phonetic (`i` for `η`) and the visual (`h` for `η`, `w` for `ω`, `8` for `θ`)
+/// spellings are read, `x` standing for `χ`. Letters without a Greek reading produce a
+/// [ValidationError::InvalidChars].
+///
+/// ```
+/// use betacode::greeklish::from_greeklish;
+/// assert_eq!(from_greeklish("kalimera").unwrap().greek, "καλιμερα");
+/// assert_eq!(from_greeklish("8ewria").unwrap().greek, "θεωρια");
+/// assert_eq!(from_greeklish("Kalhmera").unwrap().betacode, "*kalhmera");
+/// ```
+pub fn from_greeklish<T: Into<String>>(input: T) -> Result<GreekText, ValidationError> {
+    romanized_to_greek(&input.into(), greeklish_letter, char::is_ascii_alphanumeric)
+}
+#[cfg(test)]
+mod test;
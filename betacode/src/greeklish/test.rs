@@ -0,0 +1,13 @@
+use super::*;
+#[test]
+fn phonetic_and_visual() {
+    assert_eq!(from_greeklish("xara").unwrap().greek, "χαρα");
+    assert_eq!(from_greeklish("3ero").unwrap().greek, "ξερο");
+    assert_eq!(from_greeklish("kserw").unwrap().greek, "ξερω");
+    assert_eq!(from_greeklish("psyxh").unwrap().greek, "ψυχη");
+    assert_eq!(from_greeklish("ti kaneis?").unwrap().greek, "τι κανεις;");
+}
+#[test]
+fn invalid_letters() {
+    assert!(from_greeklish("jazz").is_err());
+}
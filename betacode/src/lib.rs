@@ -18,3 +18,56 @@ pub mod converter;
 
 /// Validation module for Betacode texts
 pub mod validator;
+
+/// Accentuation checks following the rules of Greek accentuation.
+pub mod accentuation;
+
+/// Syllabification and hyphenation of Greek words.
+pub mod syllables;
+
+/// Diacritic stripping and accent-insensitive comparison.
+pub mod folding;
+
+/// Latin-script transliteration of Greek.
+pub mod transliteration;
+
+/// IPA transcription following different pronunciation models.
+pub mod phonetics;
+
+/// Scansion of dactylic hexameters and elegiac pentameters.
+pub mod scansion;
+
+/// Greek alphabetic numerals.
+pub mod numerals;
+
+/// Dictionary order for Greek words.
+pub mod collation;
+
+/// Tokenization of Beta Code texts with source spans.
+pub mod tokenizer;
+
+/// Sentence and clause segmentation.
+pub mod segmenter;
+
+/// Conversion between polytonic and monotonic Greek.
+pub mod monotonic;
+
+/// ELOT 743 romanization of Modern Greek.
+pub mod elot;
+
+/// Conversion of informal Greeklish.
+pub mod greeklish;
+
+/// Greek uppercase rendering for all-caps text.
+pub mod uppercase;
+
+/// Rule-based stemming for search indexing.
+pub mod stemmer;
+
+/// Weighted edit distance and fuzzy matching of Greek words.
+pub mod fuzzy;
+
+/// Wordlist-based spell checking with suggestions.
+pub mod spellcheck;
+
+mod greek;
@@ -0,0 +1,77 @@
+use crate::converter::revert;
+use crate::greek::{
+    self, Letter, ACUTE, BREVE, CIRCUMFLEX, GRAVE, IOTA_SUBSCRIPT, MACRON, ROUGH, SMOOTH,
+};
+
+/// Monosyllables that keep their accent in the monotonic system: the disjunctive
+/// `ή` and the interrogatives `πού` and `πώς`, folded.
+const ACCENTED_MONOSYLLABLES: [&str; 3] = ["η", "που", "πωσ"];
+
+fn monotonic_word(word: &[Letter]) -> Vec<Letter> {
+    let mut output: Vec<Letter> = word
+        .iter()
+        .map(|l| Letter {
+            base: l.base,
+            marks: l
+                .marks
+                .iter()
+                .filter(|m| ![SMOOTH, ROUGH, IOTA_SUBSCRIPT, MACRON, BREVE].contains(m))
+                .map(|m| match *m {
+                    GRAVE | CIRCUMFLEX => ACUTE,
+                    m => m,
+                })
+                .collect(),
+        })
+        .collect();
+    let folded: String = word.iter().map(|l| l.lower()).collect();
+    if greek::nuclei(word).len() == 1 && !ACCENTED_MONOSYLLABLES.contains(&folded.as_str()) {
+        output
+            .iter_mut()
+            .for_each(|l| l.marks.retain(|m| *m != ACUTE));
+    }
+    output
+}
+
+/// Converts a polytonic Beta Code or Unicode text to monotonic Unicode Greek.
+///
+/// Following the 1982 reform, breathings, iota subscripts and quantity marks are dropped,
+/// the acute, grave and circumflex become the tonos and the diaeresis is kept.
+/// Monosyllables lose their accent, except `ή`, `πού` and `πώς`.
+///
+/// ```
+/// use betacode::monotonic::to_monotonic;
+/// assert_eq!(to_monotonic("ἄνθρωπος"), "άνθρωπος");
+/// assert_eq!(to_monotonic("tw=| qew=|"), "τω θεώ");
+/// assert_eq!(to_monotonic("ἢ ποῦ ἐστιν ὁ παῖς;"), "ή πού εστιν ο παις;");
+/// ```
+pub fn to_monotonic<T: Into<String>>(input: T) -> String {
+    let letters = greek::letters(greek::to_unicode(input));
+    let mut output = vec![];
+    let mut i = 0;
+    while i < letters.len() {
+        if !letters[i].is_greek() {
+            output.push(letters[i].clone());
+            i += 1;
+            continue;
+        }
+        let end = (i..letters.len())
+            .find(|j| !letters[*j].is_greek())
+            .unwrap_or(letters.len());
+        output.extend(monotonic_word(&letters[i..end]));
+        i = end;
+    }
+    greek::render(&output)
+}
+
+/// Converts a monotonic Unicode text to Beta Code, writing the tonos as an acute (`/`).
+///
+/// ```
+/// use betacode::monotonic::from_monotonic;
+/// assert_eq!(from_monotonic("Άνθρωπος"), "*a/nqrwpos");
+/// assert_eq!(from_monotonic("προϊόν"), "proi+o/n");
+/// ```
+pub fn from_monotonic<T: Into<String>>(input: T) -> String {
+    revert(input)
+}
+#[cfg(test)]
+mod test;
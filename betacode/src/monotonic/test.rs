@@ -0,0 +1,25 @@
+use super::*;
+#[test]
+fn accents_and_breathings() {
+    assert_eq!(to_monotonic("*(/omhros"), "Όμηρος");
+    assert_eq!(to_monotonic("καὶ τὴν ψυχήν"), "και την ψυχήν");
+    assert_eq!(to_monotonic("σῶμα"), "σώμα");
+    assert_eq!(to_monotonic("ἀΐδιος"), "αΐδιος");
+}
+#[test]
+fn monosyllables() {
+    assert_eq!(to_monotonic("ὁ ἥλιος ἢ ἡ γῆ"), "ο ήλιος ή η γη");
+    assert_eq!(to_monotonic("πῶς"), "πώς");
+    assert_eq!(to_monotonic("νῦν"), "νυν");
+}
+#[test]
+fn tonos_and_oxia() {
+    assert_eq!(from_monotonic("\u{03ac}"), from_monotonic("\u{1f71}"));
+    assert_eq!(from_monotonic("\u{0390}"), "i+/");
+    assert_eq!(from_monotonic(to_monotonic("ἀνθρώπῳ")), "anqrw/pw");
+}
+#[test]
+fn plain_iota() {
+    assert_eq!(from_monotonic("πρωινός"), "prwino/s");
+    assert_eq!(from_monotonic("ηρωικός"), "hrwiko/s");
+}
@@ -0,0 +1,173 @@
+use crate::converter::revert;
+use crate::greek;
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use std::fmt;
+
+/// Keraia (numeral sign) closing a Greek numeral, in its normalized form U+02B9.
+pub const KERAIA: char = '\u{02b9}';
+/// Lower keraia (lower numeral sign, U+0375) preceding the thousands.
+pub const LOWER_KERAIA: char = '\u{0375}';
+
+lazy_static! {
+    static ref RE_ARABIC_NUMBER: Regex = Regex::new(r"(^|[^#%\w\[\]<>{}$&@^])(\d+)\b").unwrap();
+}
+
+const UNITS: [char; 9] = ['α', 'β', 'γ', 'δ', 'ε', 'ϛ', 'ζ', 'η', 'θ'];
+const TENS: [char; 9] = ['ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π', 'ϟ'];
+const HUNDREDS: [char; 9] = ['ρ', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω', 'ϡ'];
+
+/// Provides the errors of the numeral conversions.
+/// - [NumeralError::OutOfRange]: the number cannot be written as a Greek numeral (1 to 9999).
+/// - [NumeralError::InvalidNumeral]: the text is not a well-formed Greek numeral.
+#[derive(Debug, PartialEq)]
+pub enum NumeralError {
+    OutOfRange(u32),
+    InvalidNumeral(String),
+}
+
+impl fmt::Display for NumeralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NumeralError::OutOfRange(a) => write!(f, "Number out of range: {}", a),
+            NumeralError::InvalidNumeral(a) => write!(f, "Invalid Greek numeral: {:?}", a),
+        }
+    }
+}
+
+/// Value of a numeral letter, accepting the alternative forms of stigma
+/// (digamma) and koppa and the capital letters.
+fn letter_value(c: char) -> Option<u32> {
+    let c = match c {
+        'ϝ' | 'Ϝ' | 'Ϛ' => 'ϛ',
+        'ϙ' | 'Ϙ' | 'Ϟ' => 'ϟ',
+        'Ϡ' => 'ϡ',
+        'ς' | 'ϲ' | 'Ϲ' => 'σ',
+        c => c.to_lowercase().next().unwrap_or(c),
+    };
+    [(UNITS, 1), (TENS, 10), (HUNDREDS, 100)]
+        .iter()
+        .find_map(|(letters, rank)| {
+            letters
+                .iter()
+                .position(|l| *l == c)
+                .map(|p| (p as u32 + 1) * rank)
+        })
+}
+
+/// Writes a number from 1 to 9999 as a Unicode Greek numeral.
+///
+/// The keraia closes the units, tens and hundreds, while the thousands are
+/// preceded by the lower keraia.
+///
+/// ```
+/// use betacode::numerals::to_greek_numeral;
+/// assert_eq!(to_greek_numeral(23).unwrap(), "κγʹ");
+/// assert_eq!(to_greek_numeral(1000).unwrap(), "͵α");
+/// assert_eq!(to_greek_numeral(1996).unwrap(), "͵αϡϟϛʹ");
+/// ```
+pub fn to_greek_numeral(number: u32) -> Result<String, NumeralError> {
+    if !(1..=9999).contains(&number) {
+        return Err(NumeralError::OutOfRange(number));
+    }
+    let mut output = String::new();
+    if number >= 1000 {
+        output.push(LOWER_KERAIA);
+        output.push(UNITS[(number / 1000) as usize - 1]);
+    }
+    let digits = [(number / 100) % 10, (number / 10) % 10, number % 10];
+    let mut rest = String::new();
+    for (digit, letters) in digits.iter().zip([HUNDREDS, TENS, UNITS]) {
+        if *digit > 0 {
+            rest.push(letters[*digit as usize - 1]);
+        }
+    }
+    if !rest.is_empty() {
+        output.push_str(&rest);
+        output.push(KERAIA);
+    }
+    Ok(output)
+}
+
+/// Writes a number from 1 to 9999 as a Beta Code Greek numeral.
+///
+/// ```
+/// use betacode::numerals::to_beta_numeral;
+/// assert_eq!(to_beta_numeral(23).unwrap(), "kg#");
+/// assert_eq!(to_beta_numeral(1996).unwrap(), "#22a#5#1#2#");
+/// ```
+pub fn to_beta_numeral(number: u32) -> Result<String, NumeralError> {
+    to_greek_numeral(number).map(revert)
+}
+
+/// Reads a Greek numeral written in Beta Code or Unicode.
+///
+/// Digamma is read as stigma, the archaic koppa as koppa and the final and lunate
+/// sigmas as sigma. The keraia may be
+/// omitted, and the Greek keraia (U+0374) and the apostrophe are accepted in its place.
+///
+/// ```
+/// use betacode::numerals::parse_greek_numeral;
+/// assert_eq!(parse_greek_numeral("κγʹ").unwrap(), 23);
+/// assert_eq!(parse_greek_numeral("#22a#5#1#2#").unwrap(), 1996);
+/// assert_eq!(parse_greek_numeral("ϙϝʹ").unwrap(), 96);
+/// ```
+pub fn parse_greek_numeral<T: Into<String>>(input: T) -> Result<u32, NumeralError> {
+    let input: String = input.into();
+    let invalid = || NumeralError::InvalidNumeral(input.clone());
+    let text = greek::to_unicode(input.trim());
+    let text = text.trim_end_matches([KERAIA, '\u{0374}', '\'', '’']);
+    if text.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut output = 0;
+    let mut previous: Option<u32> = None;
+    let mut thousands = false;
+    for c in text.chars() {
+        if c == LOWER_KERAIA {
+            if thousands {
+                return Err(invalid());
+            }
+            thousands = true;
+            continue;
+        }
+        let value = letter_value(c).ok_or_else(invalid)?;
+        let value = match thousands {
+            true if value < 10 => value * 1000,
+            true => return Err(invalid()),
+            false => value,
+        };
+        thousands = false;
+        // Each rank appears at most once, from the highest to the lowest.
+        let rank = value.ilog10();
+        if previous.is_some_and(|p| rank >= p) {
+            return Err(invalid());
+        }
+        previous = Some(rank);
+        output += value;
+    }
+    match thousands {
+        true => Err(invalid()),
+        false => Ok(output),
+    }
+}
+
+/// Replaces the Arabic numbers of a Beta Code text with Beta Code Greek numerals,
+/// leaving the Beta Code digits (`s1`, `#2`, `%26`, markup codes such as `[1`) and the
+/// numbers out of range untouched.
+pub(crate) fn arabic_to_beta<T: Into<String>>(input: T) -> String {
+    let input: String = input.into();
+    RE_ARABIC_NUMBER
+        .replace_all(&input, |c: &Captures| {
+            let numeral = c[2]
+                .parse()
+                .ok()
+                .and_then(|n| to_beta_numeral(n).ok())
+                .unwrap_or_else(|| c[2].to_string());
+            format!("{}{}", &c[1], numeral)
+        })
+        .to_string()
+}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,45 @@
+use super::*;
+#[test]
+fn to_numeral() {
+    assert_eq!(to_greek_numeral(6).unwrap(), "ϛʹ");
+    assert_eq!(to_greek_numeral(90).unwrap(), "ϟʹ");
+    assert_eq!(to_greek_numeral(2024).unwrap(), "͵βκδʹ");
+    assert_eq!(to_beta_numeral(200).unwrap(), "s#");
+    assert_eq!(to_greek_numeral(0), Err(NumeralError::OutOfRange(0)));
+    assert_eq!(
+        to_greek_numeral(10000),
+        Err(NumeralError::OutOfRange(10000))
+    );
+}
+#[test]
+fn parse_numeral() {
+    assert_eq!(parse_greek_numeral("s#").unwrap(), 200);
+    assert_eq!(parse_greek_numeral("ΡΚΓʹ").unwrap(), 123);
+    assert_eq!(parse_greek_numeral("͵βκδ").unwrap(), 2024);
+    assert_eq!(parse_greek_numeral("#22b").unwrap(), 2000);
+    assert!(parse_greek_numeral("γκʹ").is_err());
+    assert!(parse_greek_numeral("κκʹ").is_err());
+    assert!(parse_greek_numeral("λόγος").is_err());
+    assert!(parse_greek_numeral("͵κ").is_err());
+}
+#[test]
+fn round_trip() {
+    for n in (1..=9999).step_by(13) {
+        assert_eq!(
+            parse_greek_numeral(to_greek_numeral(n).unwrap()).unwrap(),
+            n
+        );
+        assert_eq!(parse_greek_numeral(to_beta_numeral(n).unwrap()).unwrap(), n);
+    }
+}
+#[test]
+fn running_text() {
+    assert_eq!(arabic_to_beta("a 12 s1 #2 %26"), "a ib# s1 #2 %26");
+}
+#[test]
+fn markup_digits() {
+    assert_eq!(
+        arabic_to_beta("[1 a ]1 <2 {3 $10 &1 @1 ^2 12"),
+        "[1 a ]1 <2 {3 $10 &1 @1 ^2 ib#"
+    );
+}
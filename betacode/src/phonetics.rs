@@ -0,0 +1,304 @@
+use crate::accentuation::Quantity;
+use crate::greek::{self, Letter, ACUTE, CIRCUMFLEX, GRAVE, IOTA_SUBSCRIPT, ROUGH};
+use crate::syllables::syllable_bounds;
+use std::ops::Range;
+use unicode_normalization::UnicodeNormalization;
+
+/// Pronunciation models supported by [to_ipa].
+///
+/// - [Pronunciation::Attic]: reconstructed Classical Attic, with vowel length, aspirated
+///   stops and the pitch accent shown as tone marks.
+/// - [Pronunciation::Koine]: Koine of the early Roman period, with fricative aspirates.
+/// - [Pronunciation::Erasmian]: the academic Erasmian pronunciation.
+/// - [Pronunciation::Modern]: Modern Greek pronunciation.
+///
+/// All models but [Pronunciation::Attic] show the accent as stress (`ˈ`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pronunciation {
+    Attic,
+    Koine,
+    Erasmian,
+    Modern,
+}
+
+const NON_SYLLABIC: &str = "\u{032f}";
+const LONG: &str = "ː";
+
+fn nucleus_ipa(word: &[Letter], nucleus: &Range<usize>, model: Pronunciation) -> String {
+    let letters = &word[nucleus.clone()];
+    let vowel: String = letters.iter().map(|l| l.lower()).collect();
+    let long = greek::quantity(word, nucleus, false) == Quantity::Long;
+    let subscript = letters[0].has(IOTA_SUBSCRIPT);
+    let next_voiceless = match word.get(nucleus.end) {
+        Some(l) => "θκξπστφχψ".contains(l.lower()),
+        None => true,
+    };
+    let ipa = match model {
+        Pronunciation::Attic => match vowel.as_str() {
+            "αι" => format!("ai{NON_SYLLABIC}"),
+            "ει" => format!("e{LONG}"),
+            "οι" => format!("oi{NON_SYLLABIC}"),
+            "υι" => format!("yi{NON_SYLLABIC}"),
+            "αυ" => format!("au{NON_SYLLABIC}"),
+            "ευ" => format!("eu{NON_SYLLABIC}"),
+            "ου" => format!("u{LONG}"),
+            "ηυ" => format!("ɛ{LONG}u{NON_SYLLABIC}"),
+            "ωυ" => format!("ɔ{LONG}u{NON_SYLLABIC}"),
+            "ε" => "e".to_string(),
+            "ο" => "o".to_string(),
+            "η" | "ω" | "α" | "ι" | "υ" => {
+                let base = match vowel.as_str() {
+                    "η" => "ɛ",
+                    "ω" => "ɔ",
+                    "υ" => "y",
+                    "ι" => "i",
+                    _ => "a",
+                };
+                match (long, subscript) {
+                    (_, true) => format!("{base}{LONG}i{NON_SYLLABIC}"),
+                    (true, false) => format!("{base}{LONG}"),
+                    (false, false) => base.to_string(),
+                }
+            }
+            _ => vowel.clone(),
+        },
+        Pronunciation::Koine => match vowel.as_str() {
+            "αι" | "ε" => "e".to_string(),
+            "ει" | "η" | "ι" => "i".to_string(),
+            "οι" | "υι" | "υ" => "y".to_string(),
+            "ου" => "u".to_string(),
+            "αυ" => "aβ".to_string(),
+            "ευ" => "eβ".to_string(),
+            "ηυ" => "iβ".to_string(),
+            "ο" | "ω" | "ωυ" => "o".to_string(),
+            "α" => "a".to_string(),
+            _ => vowel.clone(),
+        },
+        Pronunciation::Erasmian => match vowel.as_str() {
+            "αι" => format!("ai{NON_SYLLABIC}"),
+            "ει" => format!("ei{NON_SYLLABIC}"),
+            "οι" => format!("oi{NON_SYLLABIC}"),
+            "υι" => format!("yi{NON_SYLLABIC}"),
+            "αυ" => format!("au{NON_SYLLABIC}"),
+            "ευ" => format!("eu{NON_SYLLABIC}"),
+            "ου" => format!("u{LONG}"),
+            "ηυ" | "ωυ" => format!("ɛ{LONG}u{NON_SYLLABIC}"),
+            "η" => format!("ɛ{LONG}"),
+            "ω" => format!("ɔ{LONG}"),
+            "α" => "a".to_string(),
+            "ε" => "e".to_string(),
+            "ι" => "i".to_string(),
+            "ο" => "o".to_string(),
+            "υ" => "y".to_string(),
+            _ => vowel.clone(),
+        },
+        Pronunciation::Modern => match vowel.as_str() {
+            "αι" | "ε" => "e".to_string(),
+            "ει" | "οι" | "υι" | "η" | "ι" | "υ" => "i".to_string(),
+            "ου" => "u".to_string(),
+            "αυ" | "ευ" | "ηυ" => {
+                let first = match vowel.as_str() {
+                    "αυ" => "a",
+                    "ευ" => "e",
+                    _ => "i",
+                };
+                match next_voiceless {
+                    true => format!("{first}f"),
+                    false => format!("{first}v"),
+                }
+            }
+            "ο" | "ω" | "ωυ" => "o".to_string(),
+            "α" => "a".to_string(),
+            _ => vowel.clone(),
+        },
+    };
+    let rough = letters.iter().any(|l| l.has(ROUGH));
+    match rough && matches!(model, Pronunciation::Attic | Pronunciation::Erasmian) {
+        true => format!("h{ipa}"),
+        false => ipa,
+    }
+}
+
+fn is_front(ipa: &str) -> bool {
+    ipa.starts_with(['e', 'i', 'y'])
+}
+
+fn consonant_ipa(word: &[Letter], i: usize, model: Pronunciation, ipa: &[String]) -> String {
+    let letter = word[i].lower();
+    let next = word.get(i + 1).map(|l| l.lower());
+    let previous = i.checked_sub(1).map(|p| word[p].lower());
+    let front = word.get(i + 1).is_some_and(|l| l.is_vowel()) && is_front(&ipa[i + 1]);
+    let initial = i == 0;
+    let velar_next = matches!(next, Some('γ' | 'κ' | 'ξ' | 'χ'));
+    let voiced_next = matches!(next, Some('β' | 'γ' | 'δ' | 'μ'));
+    match model {
+        Pronunciation::Attic => match letter {
+            'γ' if velar_next || next == Some('μ') => "ŋ",
+            'γ' => "ɡ",
+            'ζ' => "zd",
+            'θ' => "tʰ",
+            'ξ' => "ks",
+            'ρ' if word[i].has(ROUGH) => "r\u{0325}",
+            'σ' if voiced_next => "z",
+            'φ' => "pʰ",
+            'χ' => "kʰ",
+            'ψ' => "ps",
+            _ => return common_consonant(letter),
+        }
+        .to_string(),
+        Pronunciation::Koine => match letter {
+            'β' => "β",
+            'γ' if velar_next => "ŋ",
+            'γ' if front => "ʝ",
+            'γ' => "ɣ",
+            'δ' => "ð",
+            'ζ' => "z",
+            'θ' => "θ",
+            'ξ' => "ks",
+            'φ' => "ɸ",
+            'χ' => "x",
+            'ψ' => "ps",
+            _ => return common_consonant(letter),
+        }
+        .to_string(),
+        Pronunciation::Erasmian => match letter {
+            'γ' if velar_next => "ŋ",
+            'γ' => "ɡ",
+            'ζ' => "dz",
+            'θ' => "θ",
+            'ξ' => "ks",
+            'φ' => "f",
+            'χ' => "x",
+            'ψ' => "ps",
+            _ => return common_consonant(letter),
+        }
+        .to_string(),
+        Pronunciation::Modern => {
+            if previous == Some(letter) {
+                return String::new();
+            }
+            match (letter, next) {
+                ('μ', Some('π')) | ('ν', Some('τ')) if initial => "",
+                ('γ', Some('κ')) if initial => "",
+                ('π', _) if previous == Some('μ') => "b",
+                ('τ', _) if previous == Some('ν') => "d",
+                ('κ', _) if previous == Some('γ') => "ɡ",
+                ('τ', Some('ζ')) => "d",
+                ('γ', _) if velar_next => "ŋ",
+                ('β', _) => "v",
+                ('γ', _) if front => "ʝ",
+                ('γ', _) => "ɣ",
+                ('δ', _) => "ð",
+                ('ζ', _) => "z",
+                ('θ', _) => "θ",
+                ('κ', _) if front => "c",
+                ('ξ', _) => "ks",
+                ('σ', Some('β' | 'γ' | 'δ' | 'ζ' | 'λ' | 'μ' | 'ν' | 'ρ')) => "z",
+                ('φ', _) => "f",
+                ('χ', _) if front => "ç",
+                ('χ', _) => "x",
+                ('ψ', _) => "ps",
+                _ => return common_consonant(letter),
+            }
+            .to_string()
+        }
+    }
+}
+
+fn common_consonant(letter: char) -> String {
+    match letter {
+        'β' => "b",
+        'δ' => "d",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' => "s",
+        'τ' => "t",
+        'ϝ' => "w",
+        _ => "",
+    }
+    .to_string()
+}
+
+/// Places the tone mark of the Attic model on the first vowel of the nucleus.
+fn tone(ipa: &str, accent: char) -> String {
+    let mark = match accent {
+        ACUTE => '\u{0301}',
+        CIRCUMFLEX => '\u{0302}',
+        _ => GRAVE,
+    };
+    let position = ipa
+        .char_indices()
+        .find(|(_, c)| "aeiouyɛɔ".contains(*c))
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(ipa.len());
+    format!("{}{}{}", &ipa[..position], mark, &ipa[position..])
+}
+
+fn word_ipa(word: &[Letter], model: Pronunciation) -> String {
+    let nuclei = greek::nuclei(word);
+    let mut ipa = vec![String::new(); word.len()];
+    for nucleus in &nuclei {
+        ipa[nucleus.start] = nucleus_ipa(word, nucleus, model);
+    }
+    for i in 0..word.len() {
+        if !word[i].is_vowel() {
+            ipa[i] = consonant_ipa(word, i, model, &ipa);
+        }
+    }
+    for nucleus in &nuclei {
+        let accent = word[nucleus.clone()].iter().find_map(|l| l.accent());
+        if let (Some(accent), Pronunciation::Attic) = (accent, model) {
+            ipa[nucleus.start] = tone(&ipa[nucleus.start], accent);
+        }
+    }
+    let syllables = syllable_bounds(word);
+    let mut output = String::new();
+    for syllable in &syllables {
+        let stressed = word[syllable.clone()].iter().any(|l| l.accent().is_some());
+        if stressed && syllables.len() > 1 && model != Pronunciation::Attic {
+            output.push('ˈ');
+        }
+        ipa[syllable.clone()]
+            .iter()
+            .for_each(|s| output.push_str(s));
+    }
+    output
+}
+
+/// Transcribes a Beta Code or Unicode text into IPA following the given pronunciation model.
+///
+/// ```
+/// use betacode::phonetics::{to_ipa, Pronunciation};
+/// assert_eq!(to_ipa("lo/gos", Pronunciation::Attic), "lóɡos");
+/// assert_eq!(to_ipa("λόγος", Pronunciation::Modern), "ˈloɣos");
+/// assert_eq!(to_ipa("qeo/s", Pronunciation::Attic), "tʰeós");
+/// assert_eq!(to_ipa("θεός", Pronunciation::Koine), "θeˈos");
+/// ```
+pub fn to_ipa<T: Into<String>>(input: T, model: Pronunciation) -> String {
+    let letters = greek::letters(greek::to_unicode(input));
+    let mut output = String::new();
+    let mut i = 0;
+    while i < letters.len() {
+        if !letters[i].is_greek() {
+            match letters[i].base {
+                ' ' | '\n' | '\t' => output.push(letters[i].base),
+                '.' | ';' | '·' | ',' => (),
+                c => output.push(c),
+            }
+            i += 1;
+            continue;
+        }
+        let end = (i..letters.len())
+            .find(|j| !letters[*j].is_greek())
+            .unwrap_or(letters.len());
+        output.push_str(&word_ipa(&letters[i..end], model));
+        i = end;
+    }
+    output.nfc().collect()
+}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,32 @@
+use super::*;
+#[test]
+fn attic() {
+    assert_eq!(to_ipa("μῆνιν", Pronunciation::Attic), "mɛ̂ːnin");
+    assert_eq!(to_ipa("φίλος", Pronunciation::Attic), "pʰílos");
+    assert_eq!(to_ipa("xw/ra%26", Pronunciation::Attic), "kʰɔ́ːraː");
+    assert_eq!(to_ipa("ἡ οἰκία", Pronunciation::Attic), "hɛː oi̯kía");
+    assert_eq!(to_ipa("τῷ ἀγγέλῳ", Pronunciation::Attic), "tɔ̂ːi̯ aŋɡélɔːi̯");
+    assert_eq!(to_ipa("ζεῦ", Pronunciation::Attic), "zdêu̯");
+}
+#[test]
+fn koine() {
+    assert_eq!(to_ipa("χαίρει", Pronunciation::Koine), "ˈxeri");
+    assert_eq!(to_ipa("γῆ", Pronunciation::Koine), "ʝi");
+    assert_eq!(to_ipa("ἡ οἰκία", Pronunciation::Koine), "i yˈkia");
+}
+#[test]
+fn erasmian() {
+    assert_eq!(to_ipa("φίλος", Pronunciation::Erasmian), "ˈfilos");
+    assert_eq!(to_ipa("ὁ θεός", Pronunciation::Erasmian), "ho θeˈos");
+}
+#[test]
+fn modern() {
+    assert_eq!(to_ipa("εὐχαριστῶ", Pronunciation::Modern), "efxariˈsto");
+    assert_eq!(to_ipa("αὐγή", Pronunciation::Modern), "avˈʝi");
+    assert_eq!(to_ipa("ἄλλος", Pronunciation::Modern), "ˈalos");
+    assert_eq!(
+        to_ipa("μπαίνω ἄνθρωπος", Pronunciation::Modern),
+        "ˈbeno ˈanθropos"
+    );
+    assert_eq!(to_ipa("κύριε", Pronunciation::Modern), "ˈcirie");
+}
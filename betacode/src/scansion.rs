@@ -0,0 +1,484 @@
+use crate::accentuation::Quantity;
+use crate::greek::{self, Letter, DIAERESIS, SMOOTH};
+use crate::syllables::is_onset;
+use std::fmt;
+use std::ops::Range;
+
+/// Metres handled by [scan].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meter {
+    Hexameter,
+    Pentameter,
+}
+
+/// Kinds of feet (and half-feet of the pentameter) in a [Scansion].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FootKind {
+    Dactyl,
+    Spondee,
+    Trochee,
+    Single,
+}
+
+/// Caesurae recognized by [scan].
+///
+/// - [Caesura::Penthemimeral]: after the first long of the third foot (masculine).
+/// - [Caesura::Trochaic]: after the first short of a dactylic third foot (feminine).
+/// - [Caesura::Hephthemimeral]: after the first long of the fourth foot.
+/// - [Caesura::Diaeresis]: the word end at the middle of the pentameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Caesura {
+    Penthemimeral,
+    Trochaic,
+    Hephthemimeral,
+    Diaeresis,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Foot {
+    pub kind: FootKind,
+    /// Indices of the syllables of the foot in [Scansion::syllables].
+    pub syllables: Range<usize>,
+}
+
+/// The scansion of a verse line.
+///
+/// [Scansion::quantities] holds the metrical value of each syllable, the final
+/// anceps being [Quantity::Undetermined].
+#[derive(Debug, Clone)]
+pub struct Scansion {
+    pub syllables: Vec<String>,
+    pub quantities: Vec<Quantity>,
+    pub feet: Vec<Foot>,
+    pub caesura: Option<Caesura>,
+}
+
+impl Scansion {
+    /// Renders the line as a pattern of longs (`–`) and shorts (`⏑`), the final
+    /// anceps as `×` and the feet separated by `|`.
+    ///
+    /// ```
+    /// use betacode::scansion::{scan, Meter};
+    /// let line = "mh=nin a)/eide qea\\ *phlhi+a/dew *a)xilh=os";
+    /// let result = scan(line, Meter::Hexameter).unwrap();
+    /// assert_eq!(result.pattern(), "–⏑⏑|–⏑⏑|––|–⏑⏑|–⏑⏑|–×");
+    /// ```
+    pub fn pattern(&self) -> String {
+        self.feet
+            .iter()
+            .map(|f| {
+                self.quantities[f.syllables.clone()]
+                    .iter()
+                    .map(|q| match q {
+                        Quantity::Long => '–',
+                        Quantity::Short => '⏑',
+                        Quantity::Undetermined => '×',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("|")
+    }
+}
+
+/// Provides the errors of [scan].
+/// - [ScansionError::NoSyllables]: the line has no vowels.
+/// - [ScansionError::Unmetrical]: no combination of the possible quantities fits the metre.
+#[derive(Debug)]
+pub enum ScansionError {
+    NoSyllables,
+    Unmetrical(String),
+}
+
+impl fmt::Display for ScansionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScansionError::NoSyllables => write!(f, "No syllables to scan"),
+            ScansionError::Unmetrical(a) => write!(f, "Line does not fit the metre: {:?}", a),
+        }
+    }
+}
+
+/// Quantities a syllable may take in the verse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Allowed {
+    long: bool,
+    short: bool,
+}
+
+const LONG: Allowed = Allowed {
+    long: true,
+    short: false,
+};
+const SHORT: Allowed = Allowed {
+    long: false,
+    short: true,
+};
+const EITHER: Allowed = Allowed {
+    long: true,
+    short: true,
+};
+
+/// A vowel nucleus of the line, with the index of the word it belongs to.
+struct Nucleus {
+    range: Range<usize>,
+    word: usize,
+}
+
+/// The Greek letters of a line, with the word each of them belongs to and
+/// the boundary written before each word (`’ ` after an elided word).
+struct Line {
+    letters: Vec<Letter>,
+    words: Vec<usize>,
+    word_starts: Vec<usize>,
+    boundaries: Vec<String>,
+}
+
+/// Whether a non-Greek letter is an elision mark (the koronis decomposes to a
+/// space with a smooth breathing).
+fn is_elision(letter: &Letter) -> bool {
+    matches!(letter.base, '’' | '\'' | 'ʼ') || (letter.base == ' ' && letter.has(SMOOTH))
+}
+
+fn line_letters(input: &str) -> Line {
+    let mut line = Line {
+        letters: vec![],
+        words: vec![],
+        word_starts: vec![],
+        boundaries: vec![],
+    };
+    let mut in_word = false;
+    let mut elided = false;
+    for letter in greek::letters(greek::to_unicode(input)) {
+        if !letter.is_greek() {
+            in_word = false;
+            elided |= is_elision(&letter);
+            continue;
+        }
+        if !in_word {
+            let boundary = match (line.word_starts.is_empty(), elided) {
+                (true, _) => "",
+                (false, true) => "’ ",
+                (false, false) => " ",
+            };
+            line.boundaries.push(boundary.to_string());
+            line.word_starts.push(line.letters.len());
+            in_word = true;
+            elided = false;
+        }
+        line.words.push(line.word_starts.len() - 1);
+        line.letters.push(letter);
+    }
+    line
+}
+
+fn line_nuclei(line: &Line) -> Vec<Nucleus> {
+    let mut output = vec![];
+    for (w, start) in line.word_starts.iter().enumerate() {
+        let end = line
+            .word_starts
+            .get(w + 1)
+            .copied()
+            .unwrap_or(line.letters.len());
+        for n in greek::nuclei(&line.letters[*start..end]) {
+            output.push(Nucleus {
+                range: start + n.start..start + n.end,
+                word: w,
+            });
+        }
+    }
+    output
+}
+
+fn is_stop(c: char) -> bool {
+    "πβφτδθκγχ".contains(c)
+}
+
+fn is_liquid(c: char) -> bool {
+    "λρμν".contains(c)
+}
+
+/// Quantities each syllable may take, from the nature of its vowel and from position.
+fn allowed(line: &Line, nuclei: &[Nucleus], merged: &[bool]) -> Vec<Allowed> {
+    let mut output = vec![];
+    let mut i = 0;
+    while i < nuclei.len() {
+        let nucleus = &nuclei[i];
+        if merged.get(i).copied().unwrap_or(false) {
+            output.push(LONG);
+            i += 2;
+            continue;
+        }
+        let start = *line.word_starts.get(nucleus.word).unwrap_or(&0);
+        let end = line
+            .word_starts
+            .get(nucleus.word + 1)
+            .copied()
+            .unwrap_or(line.letters.len());
+        let word = &line.letters[start..end];
+        let local = nucleus.range.start - start..nucleus.range.end - start;
+        let nature = greek::quantity(word, &local, false);
+
+        let next = nuclei
+            .get(i + 1)
+            .map(|n| n.range.start)
+            .unwrap_or(line.letters.len());
+        let cluster = &line.letters[nucleus.range.end..next];
+        let count: usize = cluster
+            .iter()
+            .map(|l| match "ζξψ".contains(l.lower()) {
+                true => 2,
+                false => 1,
+            })
+            .sum();
+        let muta_cum_liquida = cluster.len() == 2
+            && is_stop(cluster[0].lower())
+            && is_liquid(cluster[1].lower())
+            && line.words[nucleus.range.end] == line.words[nucleus.range.end + 1];
+        let hiatus =
+            cluster.is_empty() && nuclei.get(i + 1).is_some_and(|n| n.word != nucleus.word);
+
+        let syllable = match (count, nature) {
+            (2.., _) if muta_cum_liquida && nature != Quantity::Long => EITHER,
+            (2.., _) => LONG,
+            (_, Quantity::Long) if hiatus => EITHER,
+            (_, Quantity::Long) => LONG,
+            (_, Quantity::Short) => SHORT,
+            (_, Quantity::Undetermined) => EITHER,
+        };
+        output.push(syllable);
+        i += 1;
+    }
+    output
+}
+
+/// Metrical positions: `Some(true)` long, `Some(false)` short, `None` anceps.
+fn feet_options(meter: Meter, foot: usize) -> Vec<(FootKind, Vec<Option<bool>>)> {
+    let dactyl = (FootKind::Dactyl, vec![Some(true), Some(false), Some(false)]);
+    let spondee = (FootKind::Spondee, vec![Some(true), Some(true)]);
+    match (meter, foot) {
+        (Meter::Hexameter, 0..=4) => vec![dactyl, spondee],
+        (Meter::Hexameter, 5) => vec![(FootKind::Spondee, vec![Some(true), None])],
+        (Meter::Pentameter, 0 | 1) => vec![dactyl, spondee],
+        (Meter::Pentameter, 2) => vec![(FootKind::Single, vec![Some(true)])],
+        (Meter::Pentameter, 3 | 4) => vec![dactyl],
+        (Meter::Pentameter, 5) => vec![(FootKind::Single, vec![None])],
+        _ => vec![],
+    }
+}
+
+fn fit(
+    allowed: &[Allowed],
+    meter: Meter,
+    foot: usize,
+    start: usize,
+    output: &mut Vec<(FootKind, Vec<Option<bool>>)>,
+) -> bool {
+    if foot == 6 {
+        return start == allowed.len();
+    }
+    for (kind, positions) in feet_options(meter, foot) {
+        let end = start + positions.len();
+        if end > allowed.len() {
+            continue;
+        }
+        let fits = positions
+            .iter()
+            .zip(&allowed[start..end])
+            .all(|(p, a)| match p {
+                Some(true) => a.long,
+                Some(false) => a.short,
+                None => true,
+            });
+        if fits {
+            output.push((kind, positions));
+            if fit(allowed, meter, foot + 1, end, output) {
+                return true;
+            }
+            output.pop();
+        }
+    }
+    false
+}
+
+/// Texts of each syllable, keeping the consonants of an elided word
+/// with the following syllable, after the elision mark (`δ’ ἐ`).
+fn syllable_texts(line: &Line, nuclei: &[Nucleus]) -> Vec<String> {
+    let mut bounds = vec![0];
+    for pair in nuclei.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+        let split = match a.word == b.word {
+            true => {
+                let cluster = &line.letters[a.range.end..b.range.start];
+                let onset = (0..=cluster.len())
+                    .find(|i| is_onset(&cluster[*i..]))
+                    .unwrap_or(cluster.len());
+                a.range.end + onset
+            }
+            false => line.word_starts[a.word + 1],
+        };
+        bounds.push(split);
+    }
+    bounds.push(line.letters.len());
+    bounds
+        .windows(2)
+        .map(|b| {
+            let mut text = String::new();
+            let mut start = b[0];
+            for (w, word_start) in line.word_starts.iter().enumerate() {
+                if (b[0] + 1..b[1]).contains(word_start) {
+                    text.push_str(&greek::render(&line.letters[start..*word_start]));
+                    text.push_str(&line.boundaries[w]);
+                    start = *word_start;
+                }
+            }
+            text.push_str(&greek::render(&line.letters[start..b[1]]));
+            text
+        })
+        .collect()
+}
+
+fn caesura(meter: Meter, feet: &[Foot], word_ends: &[bool]) -> Option<Caesura> {
+    let after = |syllable: usize| word_ends.get(syllable).copied().unwrap_or(false);
+    match meter {
+        Meter::Hexameter => {
+            let third = &feet[2];
+            if after(third.syllables.start) {
+                Some(Caesura::Penthemimeral)
+            } else if third.kind == FootKind::Dactyl && after(third.syllables.start + 1) {
+                Some(Caesura::Trochaic)
+            } else if after(feet[3].syllables.start) {
+                Some(Caesura::Hephthemimeral)
+            } else {
+                None
+            }
+        }
+        Meter::Pentameter => match after(feet[2].syllables.start) {
+            true => Some(Caesura::Diaeresis),
+            false => None,
+        },
+    }
+}
+
+/// Scans a verse line in Beta Code or Unicode as a dactylic hexameter or an elegiac pentameter.
+///
+/// Quantity marks (`%26`, `%27`) are used when present; unmarked `α`, `ι` and `υ`, muta cum
+/// liquida and long vowels in hiatus before another word (epic correption) may count either way.
+/// Elided words join the following syllable, and vowels in hiatus within a word may be
+/// merged by synizesis when no other scansion fits.
+///
+/// ```
+/// use betacode::scansion::{scan, Caesura, Meter};
+/// let line = "μῆνιν ἄειδε θεὰ Πηληϊάδεω Ἀχιλῆος";
+/// let result = scan(line, Meter::Hexameter).unwrap();
+/// assert_eq!(result.syllables[11], "δεω");
+/// assert_eq!(result.caesura, Some(Caesura::Penthemimeral));
+/// ```
+pub fn scan<T: Into<String>>(line: T, meter: Meter) -> Result<Scansion, ScansionError> {
+    let input: String = line.into();
+    let line = line_letters(&input);
+    let nuclei = line_nuclei(&line);
+    if nuclei.is_empty() {
+        return Err(ScansionError::NoSyllables);
+    }
+
+    let candidates: Vec<usize> = (0..nuclei.len() - 1)
+        .filter(|i| {
+            let (a, b) = (&nuclei[*i], &nuclei[i + 1]);
+            a.word == b.word
+                && a.range.end == b.range.start
+                && !line.letters[b.range.start].has(DIAERESIS)
+        })
+        .collect();
+    let mut subsets: Vec<Vec<usize>> = (0..1usize << candidates.len().min(10))
+        .map(|mask| {
+            candidates
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| mask & (1 << bit) != 0)
+                .map(|(_, c)| *c)
+                .collect()
+        })
+        .collect();
+    subsets.sort_by_key(|s: &Vec<usize>| s.len());
+
+    for subset in subsets {
+        if subset.windows(2).any(|w| w[1] == w[0] + 1) {
+            continue;
+        }
+        let mut merged = vec![false; nuclei.len()];
+        subset.iter().for_each(|i| merged[*i] = true);
+        let allowed = allowed(&line, &nuclei, &merged);
+        let mut chosen = vec![];
+        if !fit(&allowed, meter, 0, 0, &mut chosen) {
+            continue;
+        }
+
+        let kept: Vec<Nucleus> = (0..nuclei.len())
+            .filter(|i| *i == 0 || !merged[i - 1])
+            .map(|i| Nucleus {
+                range: match merged[i] {
+                    true => nuclei[i].range.start..nuclei[i + 1].range.end,
+                    false => nuclei[i].range.clone(),
+                },
+                word: nuclei[i].word,
+            })
+            .collect();
+        let syllables = syllable_texts(&line, &kept);
+        let word_ends: Vec<bool> = (0..kept.len())
+            .map(|i| kept.get(i + 1).is_none_or(|n| n.word != kept[i].word))
+            .collect();
+
+        let mut feet = vec![];
+        let mut quantities = vec![];
+        let mut start = 0;
+        for (kind, positions) in chosen {
+            let end = start + positions.len();
+            let kind = match (kind, positions.last()) {
+                (FootKind::Spondee, Some(None)) if !allowed[end - 1].long => FootKind::Trochee,
+                _ => kind,
+            };
+            quantities.extend(positions.iter().map(|p| match p {
+                Some(true) => Quantity::Long,
+                Some(false) => Quantity::Short,
+                None => Quantity::Undetermined,
+            }));
+            feet.push(Foot {
+                kind,
+                syllables: start..end,
+            });
+            start = end;
+        }
+        let caesura = caesura(meter, &feet, &word_ends);
+        return Ok(Scansion {
+            syllables,
+            quantities,
+            feet,
+            caesura,
+        });
+    }
+    Err(ScansionError::Unmetrical(input))
+}
+
+/// Scans an elegiac text, alternating hexameters and pentameters line by line.
+///
+/// ```
+/// use betacode::scansion::scan_elegiac;
+/// let couplet = "ὦ ξεῖν’, ἀγγέλλειν Λακεδαιμονίοις ὅτι τῇδε\nκείμεθα τοῖς κείνων ῥήμασι πειθόμενοι.";
+/// let result = scan_elegiac(couplet).unwrap();
+/// assert_eq!(result[1].pattern(), "–⏑⏑|––|–|–⏑⏑|–⏑⏑|×");
+/// ```
+pub fn scan_elegiac<T: Into<String>>(input: T) -> Result<Vec<Scansion>, ScansionError> {
+    let input: String = input.into();
+    input
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .enumerate()
+        .map(|(i, l)| match i % 2 {
+            0 => scan(l, Meter::Hexameter),
+            _ => scan(l, Meter::Pentameter),
+        })
+        .collect()
+}
+#[cfg(test)]
+mod test;
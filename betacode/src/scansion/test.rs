@@ -0,0 +1,63 @@
+use super::*;
+#[test]
+fn hexameter() {
+    let result = scan("μῆνιν ἄειδε θεὰ Πηληϊάδεω Ἀχιλῆος", Meter::Hexameter).unwrap();
+    assert_eq!(result.pattern(), "–⏑⏑|–⏑⏑|––|–⏑⏑|–⏑⏑|–×");
+    assert_eq!(result.syllables.len(), 16);
+    assert_eq!(result.feet[2].kind, FootKind::Spondee);
+    assert_eq!(result.caesura, Some(Caesura::Penthemimeral));
+}
+#[test]
+fn elision() {
+    let result = scan(
+        "ὦ ξεῖν’, ἀγγέλλειν Λακεδαιμονίοις ὅτι τῇδε",
+        Meter::Hexameter,
+    )
+    .unwrap();
+    assert_eq!(result.pattern(), "––|––|–⏑⏑|–⏑⏑|–⏑⏑|–×");
+    assert_eq!(result.syllables[1], "ξεῖν");
+    assert_eq!(result.caesura, Some(Caesura::Penthemimeral));
+}
+#[test]
+fn pentameter() {
+    let result = scan("κείμεθα τοῖς κείνων ῥήμασι πειθόμενοι", Meter::Pentameter).unwrap();
+    assert_eq!(result.pattern(), "–⏑⏑|––|–|–⏑⏑|–⏑⏑|×");
+    assert_eq!(result.feet[5].kind, FootKind::Single);
+    assert_eq!(result.caesura, Some(Caesura::Diaeresis));
+}
+#[test]
+fn errors() {
+    assert!(matches!(
+        scan("μῆνιν ἄειδε", Meter::Hexameter),
+        Err(ScansionError::Unmetrical(_))
+    ));
+    assert!(matches!(
+        scan("...", Meter::Hexameter),
+        Err(ScansionError::NoSyllables)
+    ));
+}
+#[test]
+fn elided_word() {
+    let result = scan(
+        "οἰωνοῖσί τε πᾶσι, Διὸς δ’ ἐτελείετο βουλή",
+        Meter::Hexameter,
+    )
+    .unwrap();
+    assert_eq!(result.pattern(), "––|–⏑⏑|–⏑⏑|–⏑⏑|–⏑⏑|–×");
+    assert_eq!(result.syllables[9], "δ’ ἐ");
+    assert_eq!(result.caesura, Some(Caesura::Trochaic));
+    let result = scan(
+        r"ou)lome/nhn, h(\ muri/' *)axaioi=s a)/lge' e)/qhke",
+        Meter::Hexameter,
+    );
+    let result = result.unwrap();
+    assert_eq!(result.pattern(), "–⏑⏑|––|–⏑⏑|––|–⏑⏑|–×");
+    assert_eq!(result.syllables[6], "ρί");
+    let result = scan(
+        r"oi)wnoi=si/ te pa=si, *dio\s d' e)telei/eto boulh/",
+        Meter::Hexameter,
+    )
+    .unwrap();
+    assert_eq!(result.syllables[8], "ὸς");
+    assert_eq!(result.syllables[9], "δ’ ἐ");
+}
@@ -0,0 +1,187 @@
+use crate::converter::convert;
+use crate::folding::fold;
+use crate::greek;
+use std::ops::Range;
+
+/// Abbreviations whose final period does not end a sentence, folded and without their periods.
+pub const ABBREVIATIONS: [&str; 7] = ["κτλ", "κτε", "πρβλ", "βλ", "σελ", "κεφ", "σχολ"];
+
+/// Kinds of [Segment].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentKind {
+    Sentence,
+    Clause,
+}
+
+/// A sentence or clause of a text.
+///
+/// `span` is the byte range of the segment in the source, `text` its Unicode form
+/// and `sentence` the index of the sentence the segment belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub kind: SegmentKind,
+    pub span: Range<usize>,
+    pub text: String,
+    pub sentence: usize,
+}
+
+/// Options of [segment_with].
+///
+/// The default splits into sentences, with the [ABBREVIATIONS] of the crate.
+#[derive(Debug, Clone)]
+pub struct SegmentOptions {
+    /// Splits the sentences into clauses at the ano teleia and the comma.
+    pub clauses: bool,
+    /// Abbreviations whose final period does not end a sentence, in Beta Code or Unicode.
+    pub abbreviations: Vec<String>,
+}
+
+impl Default for SegmentOptions {
+    fn default() -> Self {
+        SegmentOptions {
+            clauses: false,
+            abbreviations: ABBREVIATIONS.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+}
+
+/// Punctuation marks closing a segment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Boundary {
+    Clause,
+    Sentence,
+}
+
+fn boundary(c: char, betacode: bool) -> Option<Boundary> {
+    match (c, betacode) {
+        ('.' | ';', _) | ('\u{037e}', false) => Some(Boundary::Sentence),
+        (':' | ',', _) | ('·' | '\u{0387}', false) => Some(Boundary::Clause),
+        _ => None,
+    }
+}
+
+/// The word (with its inner periods) that ends at the given byte.
+fn word_before(input: &str, end: usize) -> &str {
+    let start = input[..end]
+        .rfind(|c: char| c.is_whitespace() || "[]()<>{}".contains(c))
+        .map_or(0, |i| i + 1);
+    &input[start..end]
+}
+
+fn is_numeral(word: &str, betacode: bool) -> bool {
+    let numeral_sign = match betacode {
+        true => word.ends_with('#') || word.starts_with("#22"),
+        false => word.ends_with(['ʹ', '\u{0374}']) || word.starts_with('͵'),
+    };
+    numeral_sign || (!word.is_empty() && word.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Whether the period ending at `i` belongs to an abbreviation or a numeral rather
+/// than ending the sentence.
+fn is_inner_period(input: &str, i: usize, abbreviations: &[String], segment_start: usize) -> bool {
+    let betacode = greek::is_betacode(input);
+    let word = word_before(input, i);
+    let next = input[i + 1..].chars().next();
+    if next.is_some_and(|c| c.is_ascii_digit()) && word.ends_with(|c: char| c.is_ascii_digit()) {
+        return true;
+    }
+    let start = i - word.len();
+    if is_numeral(word, betacode) && input[segment_start..start].trim().is_empty() {
+        return true;
+    }
+    let end = input[i..]
+        .find(char::is_whitespace)
+        .map_or(input.len(), |e| i + e);
+    let folded = fold(&input[start..end]).replace('.', "");
+    !folded.is_empty() && abbreviations.contains(&folded)
+}
+
+/// Splits a Beta Code or Unicode text into sentences at the full stop and the question mark.
+///
+/// ```
+/// use betacode::segmenter::segment;
+/// let result = segment("ti/ fh/s; ou)de\\n le/gw. kai\\ ta\\ loipa/");
+/// assert_eq!(result.len(), 3);
+/// assert_eq!(result[0].text, "τί φής;");
+/// assert_eq!(result[1].span, 10..24);
+/// ```
+pub fn segment<T: Into<String>>(input: T) -> Vec<Segment> {
+    segment_with(input, &SegmentOptions::default())
+}
+
+/// Splits a Beta Code or Unicode text into sentences, or into clauses, with the given options.
+///
+/// Periods closing an abbreviation or a numeral at the beginning of a sentence
+/// (as in numbered sections) do not end the sentence.
+///
+/// ```
+/// use betacode::segmenter::{segment_with, SegmentKind, SegmentOptions};
+/// let options = SegmentOptions {
+///     clauses: true,
+///     ..Default::default()
+/// };
+/// let result = segment_with("αʹ. ἦλθεν, εἶδεν· ἐνίκησε. τί;", &options);
+/// let texts: Vec<&str> = result.iter().map(|s| s.text.as_str()).collect();
+/// assert_eq!(texts, vec!["αʹ. ἦλθεν,", "εἶδεν·", "ἐνίκησε.", "τί;"]);
+/// assert_eq!(result[3].kind, SegmentKind::Clause);
+/// assert_eq!(result[3].sentence, 1);
+/// ```
+pub fn segment_with<T: Into<String>>(input: T, options: &SegmentOptions) -> Vec<Segment> {
+    let input: String = input.into();
+    let betacode = greek::is_betacode(&input);
+    let abbreviations: Vec<String> = options
+        .abbreviations
+        .iter()
+        .map(|a| fold(a).replace('.', ""))
+        .collect();
+    let kind = match options.clauses {
+        true => SegmentKind::Clause,
+        false => SegmentKind::Sentence,
+    };
+
+    let mut output = vec![];
+    let mut sentence = 0;
+    let mut start = 0;
+    let mut sentence_start = 0;
+    let mut push = |start: usize, end: usize, sentence: usize| {
+        let text = &input[start..end];
+        let offset = text.len() - text.trim_start().len();
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        let span = start + offset..start + offset + text.len();
+        output.push(Segment {
+            kind,
+            text: match betacode {
+                true => convert(text),
+                false => text.to_string(),
+            },
+            span,
+            sentence,
+        });
+    };
+    for (i, c) in input.char_indices() {
+        let end = i + c.len_utf8();
+        match boundary(c, betacode) {
+            Some(Boundary::Sentence) => {
+                if c == '.' && is_inner_period(&input, i, &abbreviations, sentence_start) {
+                    continue;
+                }
+                push(start, end, sentence);
+                sentence += 1;
+                start = end;
+                sentence_start = end;
+            }
+            Some(Boundary::Clause) if options.clauses => {
+                push(start, end, sentence);
+                start = end;
+            }
+            _ => (),
+        }
+    }
+    push(start, input.len(), sentence);
+    output
+}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,33 @@
+use super::*;
+#[test]
+fn sentences() {
+    let result = segment("ἦλθεν. εἶδεν; ἐνίκησε");
+    let texts: Vec<&str> = result.iter().map(|s| s.text.as_str()).collect();
+    assert_eq!(texts, vec!["ἦλθεν.", "εἶδεν;", "ἐνίκησε"]);
+    assert_eq!(result[1].span, 13..25);
+    assert_eq!(result[2].sentence, 2);
+}
+#[test]
+fn clauses_in_betacode() {
+    let options = SegmentOptions {
+        clauses: true,
+        ..Default::default()
+    };
+    let input = "h)=lqen, ei)=den: e)ni/khse.";
+    let result = segment_with(input, &options);
+    assert_eq!(result.len(), 3);
+    assert_eq!(result[1].text, "εἶδεν·");
+    assert_eq!(&input[result[1].span.clone()], "ei)=den:");
+    assert!(result.iter().all(|s| s.sentence == 0));
+}
+#[test]
+fn abbreviations_and_numerals() {
+    assert_eq!(segment("le/gei k.t.l. kai\\ a)/llo").len(), 1);
+    assert_eq!(segment("ἐν τῷ 1.23 λέγει").len(), 1);
+    assert_eq!(segment("12. ἦλθεν. εἶδεν").len(), 2);
+    let options = SegmentOptions {
+        abbreviations: vec![String::from("ἀρ.")],
+        ..Default::default()
+    };
+    assert_eq!(segment_with("ἀρ. κτλ. ἦλθεν", &options).len(), 2);
+}
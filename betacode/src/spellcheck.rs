@@ -0,0 +1,167 @@
+use crate::accentuation::{citation_form_with, CitationOptions};
+use crate::fuzzy::{letters_distance, Weights};
+use crate::greek::{self, Letter};
+use crate::tokenizer::{tokenize, TokenKind};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::ops::Range;
+use std::path::Path;
+
+/// Suggestions given for each unknown word, at most.
+const MAX_SUGGESTIONS: usize = 5;
+/// Largest [distance](crate::fuzzy::distance) of a suggestion.
+const MAX_DISTANCE: f32 = 2.0;
+/// Largest difference in letters between a word and its suggestions.
+const MAX_LENGTH_DIFFERENCE: usize = 2;
+
+/// An unknown word found by [SpellChecker::check].
+///
+/// `span` is the byte range of the word in the Beta Code source, `line` and
+/// `column` the (1-based) position of its first character, and `suggestions`
+/// the closest words of the wordlist, closest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpellingError {
+    pub word: String,
+    pub span: Range<usize>,
+    pub line: usize,
+    pub column: usize,
+    pub suggestions: Vec<String>,
+}
+
+impl fmt::Display for SpellingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: Unknown word {:?}",
+            self.line, self.column, self.word
+        )?;
+        match self.suggestions.is_empty() {
+            true => Ok(()),
+            false => write!(f, ", did you mean {:?}", self.suggestions),
+        }
+    }
+}
+
+/// Spell checker built from a Greek wordlist, in Beta Code or Unicode.
+///
+/// Words are looked up in their citation form, lowercased, so that graves
+/// and capitals at the beginning of a sentence are accepted.
+/// Words of the wordlist with the same citation form are only kept once.
+#[derive(Debug, Clone, Default)]
+pub struct SpellChecker {
+    words: Vec<String>,
+    keys: HashSet<String>,
+    /// Letters of each word with its index, grouped by their number.
+    letters: BTreeMap<usize, Vec<(usize, Vec<Letter>)>>,
+}
+
+fn key(word: &str) -> String {
+    let options = CitationOptions { lowercase: true };
+    citation_form_with(greek::to_unicode(word), &options)
+}
+
+impl SpellChecker {
+    /// Builds a spell checker from Beta Code or Unicode words.
+    ///
+    /// ```
+    /// use betacode::spellcheck::SpellChecker;
+    /// let checker = SpellChecker::new(["lo/gos", "θεά"]);
+    /// assert!(checker.contains("λόγος"));
+    /// assert!(checker.contains("qea\\"));
+    /// ```
+    pub fn new<I: IntoIterator<Item = T>, T: Into<String>>(words: I) -> Self {
+        let mut checker = SpellChecker::default();
+        for word in words {
+            let word: String = word.into();
+            // Words sharing a key are kept once, as first listed
+            if !checker.keys.insert(key(&word)) {
+                continue;
+            }
+            let letters = greek::letters(greek::to_unicode(word.as_str()));
+            checker
+                .letters
+                .entry(letters.len())
+                .or_default()
+                .push((checker.words.len(), letters));
+            checker.words.push(word);
+        }
+        checker
+    }
+
+    /// Builds a spell checker from the text of a wordlist, with words separated by whitespace.
+    pub fn from_wordlist<T: Into<String>>(input: T) -> Self {
+        SpellChecker::new(input.into().split_whitespace())
+    }
+
+    /// Builds a spell checker from a wordlist file, as [SpellChecker::from_wordlist].
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, std::io::Error> {
+        Ok(SpellChecker::from_wordlist(std::fs::read_to_string(path)?))
+    }
+
+    /// Whether a Beta Code or Unicode word is in the wordlist.
+    pub fn contains<T: Into<String>>(&self, word: T) -> bool {
+        self.keys.contains(&key(&word.into()))
+    }
+
+    /// Words of the wordlist closest to a Beta Code or Unicode word, by [distance](crate::fuzzy::distance).
+    ///
+    /// ```
+    /// use betacode::spellcheck::SpellChecker;
+    /// let checker = SpellChecker::new(["λόγος", "λόγου", "ἔργον"]);
+    /// assert_eq!(checker.suggest("λώγος"), vec!["λόγος", "λόγου"]);
+    /// ```
+    pub fn suggest<T: Into<String>>(&self, word: T) -> Vec<String> {
+        let word = greek::letters(greek::to_unicode(word));
+        let weights = Weights::default();
+        let lengths =
+            word.len().saturating_sub(MAX_LENGTH_DIFFERENCE)..=word.len() + MAX_LENGTH_DIFFERENCE;
+        let mut found: Vec<(f32, usize)> = self
+            .letters
+            .range(lengths)
+            .flat_map(|(_, entries)| entries)
+            .map(|(i, w)| (letters_distance(&word, w, &weights, MAX_DISTANCE), *i))
+            .filter(|(d, _)| *d <= MAX_DISTANCE)
+            .collect();
+        found.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        found
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, i)| self.words[i].clone())
+            .collect()
+    }
+
+    /// Checks the words of a Beta Code document against the wordlist.
+    ///
+    /// Elided words, numbers and markup are not checked.
+    ///
+    /// ```
+    /// use betacode::spellcheck::SpellChecker;
+    /// let checker = SpellChecker::new(["μῆνιν", "ἄειδε", "θεά"]);
+    /// let errors = checker.check("mh=nin a)/eide\nqew\\");
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].word, "qew\\");
+    /// assert_eq!((errors[0].line, errors[0].column), (2, 1));
+    /// assert_eq!(errors[0].suggestions, vec!["θεά"]);
+    /// ```
+    pub fn check<T: Into<String>>(&self, input: T) -> Vec<SpellingError> {
+        let input: String = input.into();
+        tokenize(input.as_str())
+            .into_iter()
+            .filter(|t| t.kind == TokenKind::Word && !t.greek_text.ends_with('’'))
+            .filter(|t| !self.contains(t.greek_text.as_str()))
+            .map(|t| {
+                let start = t.beta_span.start;
+                let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+                SpellingError {
+                    word: input[t.beta_span.clone()].to_string(),
+                    line: input[..start].matches('\n').count() + 1,
+                    column: start - line_start + 1,
+                    suggestions: self.suggest(t.greek_text),
+                    span: t.beta_span,
+                }
+            })
+            .collect()
+    }
+}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn lookup() {
+    let checker = SpellChecker::from_wordlist("a)/nqrwpos\nλόγος θεός\n");
+    assert!(checker.contains("a)/nqrwpos"));
+    assert!(checker.contains("Ἄνθρωπος"));
+    assert!(checker.contains("qeo\\s"));
+    assert!(!checker.contains("qeo/n"));
+    assert!(!checker.contains("λογος"));
+}
+#[test]
+fn errors() {
+    let checker = SpellChecker::new(["ὁ", "λόγος", "καί", "ὁ", "μῦθος"]);
+    let errors = checker.check("o( lo/gos kai\\ o( mu=tos\n*lw/gos d' e)sti/n. 12");
+    let words: Vec<&str> = errors.iter().map(|e| e.word.as_str()).collect();
+    assert_eq!(words, vec!["mu=tos", "*lw/gos", "e)sti/n"]);
+    assert_eq!(errors[0].span, 18..24);
+    assert_eq!((errors[1].line, errors[1].column), (2, 1));
+    assert_eq!(errors[0].suggestions, vec!["μῦθος"]);
+    assert_eq!(errors[1].suggestions[0], "λόγος");
+    assert!(errors[2].suggestions.is_empty());
+}
+#[test]
+fn display() {
+    let checker = SpellChecker::new(["λόγος"]);
+    let errors = checker.check("lo/gou");
+    assert_eq!(
+        errors[0].to_string(),
+        "1:1: Unknown word \"lo/gou\", did you mean [\"λόγος\"]"
+    );
+}
+#[test]
+fn suggestions_by_length() {
+    let checker = SpellChecker::new(["λόγος", "λόγοισι", "λόγοι", "λό", "λόγ"]);
+    assert_eq!(
+        checker.suggest("lo/gos"),
+        vec!["λόγος", "λόγοι", "λόγοισι", "λόγ"]
+    );
+}
+#[test]
+fn duplicate_words() {
+    let checker = SpellChecker::new(["λόγος", "λόγος", "lo/gos", "λόγοι"]);
+    assert_eq!(checker.suggest("lo/gou"), vec!["λόγος", "λόγοι"]);
+}
+#[test]
+fn dangling_asterisk() {
+    let checker = SpellChecker::new(["α", "β"]);
+    assert!(checker.check("a * b").is_empty());
+    let errors = checker.check("a * g");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].span, 4..5);
+}
@@ -0,0 +1,247 @@
+use crate::folding::fold;
+
+/// Ancient Greek function words (articles, particles, conjunctions, prepositions and
+/// common pronouns), folded as by [fold].
+pub const STOPWORDS: [&str; 72] = [
+    "ο",
+    "η",
+    "το",
+    "οι",
+    "αι",
+    "τα",
+    "του",
+    "τησ",
+    "των",
+    "τω",
+    "τη",
+    "τοισ",
+    "ταισ",
+    "τον",
+    "την",
+    "τουσ",
+    "τασ",
+    "και",
+    "δε",
+    "δ",
+    "τε",
+    "τ",
+    "γαρ",
+    "μεν",
+    "ουν",
+    "αλλα",
+    "αλλ",
+    "ου",
+    "ουκ",
+    "ουχ",
+    "μη",
+    "γε",
+    "αν",
+    "εαν",
+    "ει",
+    "ωσ",
+    "οτι",
+    "ουτε",
+    "μητε",
+    "ουδε",
+    "μηδε",
+    "επει",
+    "ινα",
+    "εν",
+    "εισ",
+    "εσ",
+    "εκ",
+    "εξ",
+    "απο",
+    "απ",
+    "αφ",
+    "επι",
+    "επ",
+    "εφ",
+    "προσ",
+    "περι",
+    "κατα",
+    "κατ",
+    "μετα",
+    "μετ",
+    "παρα",
+    "παρ",
+    "υπο",
+    "υπ",
+    "υπερ",
+    "δια",
+    "συν",
+    "προ",
+    "αντι",
+    "αυτοσ",
+    "αυτου",
+    "αυτον",
+];
+
+/// Endings of nouns and adjectives of the three declensions, folded.
+const NOMINAL_ENDINGS: [&str; 29] = [
+    "οισιν",
+    "αισιν",
+    "οισι",
+    "αισι",
+    "ησι",
+    "εσσι",
+    "οισ",
+    "αισ",
+    "ουσ",
+    "ων",
+    "ασ",
+    "ησ",
+    "οσ",
+    "ου",
+    "ον",
+    "ην",
+    "αν",
+    "εσ",
+    "ιν",
+    "οι",
+    "αι",
+    "υσ",
+    "ισ",
+    "ω",
+    "η",
+    "α",
+    "ο",
+    "ι",
+    "ε",
+];
+
+/// Endings of finite verbs, infinitives and participles, folded.
+const VERBAL_ENDINGS: [&str; 36] = [
+    "ομεθα",
+    "εσθαι",
+    "εσθε",
+    "ονται",
+    "οντο",
+    "ουσιν",
+    "ομαι",
+    "εται",
+    "ηται",
+    "οιτο",
+    "ομεν",
+    "ουσι",
+    "ουσα",
+    "οντα",
+    "οντοσ",
+    "οντεσ",
+    "οντι",
+    "οντων",
+    "μενοσ",
+    "μενη",
+    "μενον",
+    "μενοι",
+    "μεναι",
+    "μενων",
+    "σθαι",
+    "ετε",
+    "ειν",
+    "εισ",
+    "ει",
+    "ειτε",
+    "οιμι",
+    "οισ",
+    "οι",
+    "εν",
+    "ατε",
+    "αμεν",
+];
+
+/// Tense markers of the future, the aorist and the aorist passive, folded.
+const TENSE_MARKERS: [&str; 3] = ["θησ", "θη", "σ"];
+
+/// Letters always kept in a stem.
+const MIN_STEM: usize = 2;
+
+/// How much of a word [stem_with] removes.
+///
+/// - [Aggressiveness::Light]: the endings of nouns and adjectives.
+/// - [Aggressiveness::Medium]: also the endings of verbs, the default.
+/// - [Aggressiveness::Aggressive]: also the syllabic augment (`ε-`) and the tense markers
+///   (`-σ-`, `-θη-`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Aggressiveness {
+    Light,
+    #[default]
+    Medium,
+    Aggressive,
+}
+
+/// Options of [stem_with].
+#[derive(Debug, Clone, Default)]
+pub struct StemOptions {
+    pub aggressiveness: Aggressiveness,
+}
+
+/// Removes the longest of the suffixes leaving at least [MIN_STEM] letters.
+fn strip_suffix(word: &[char], suffixes: &[&str]) -> usize {
+    suffixes
+        .iter()
+        .map(|s| s.chars().collect::<Vec<char>>())
+        .filter(|s| word.len() >= s.len() + MIN_STEM && word.ends_with(s))
+        .map(|s| s.len())
+        .max()
+        .map_or(word.len(), |len| word.len() - len)
+}
+
+/// Stems a Beta Code or Unicode word, as [stem_with] with the default options.
+///
+/// ```
+/// use betacode::stemmer::stem;
+/// assert_eq!(stem("λόγος"), "λογ");
+/// assert_eq!(stem("lo/gou"), "λογ");
+/// assert_eq!(stem("λόγῳ"), "λογ");
+/// ```
+pub fn stem<T: Into<String>>(word: T) -> String {
+    stem_with(word, &StemOptions::default())
+}
+
+/// Stems a Beta Code or Unicode word into a folded stem (lowercase Unicode Greek
+/// without diacritics), by removing its inflectional endings.
+///
+/// The rules are not morphological analysis: stems may be shared by unrelated words,
+/// and stems changed by ablaut or contraction are not reconciled.
+///
+/// ```
+/// use betacode::stemmer::{stem_with, Aggressiveness, StemOptions};
+/// let options = StemOptions {
+///     aggressiveness: Aggressiveness::Aggressive,
+/// };
+/// assert_eq!(stem_with("e)/lusa", &options), "λυ");
+/// assert_eq!(stem_with("λύομεν", &options), "λυ");
+/// ```
+pub fn stem_with<T: Into<String>>(word: T, options: &StemOptions) -> String {
+    let word: Vec<char> = fold(word).chars().collect();
+    let mut suffixes = NOMINAL_ENDINGS.to_vec();
+    if options.aggressiveness != Aggressiveness::Light {
+        suffixes.extend(VERBAL_ENDINGS);
+    }
+    let mut end = strip_suffix(&word, &suffixes);
+    let mut start = 0;
+    if options.aggressiveness == Aggressiveness::Aggressive {
+        end = strip_suffix(&word[..end], &TENSE_MARKERS);
+        let augment =
+            word.first() == Some(&'ε') && word.get(1).is_some_and(|c| !"αεηιουω".contains(*c));
+        if end > MIN_STEM && augment {
+            start = 1;
+        }
+    }
+    word[start..end].iter().collect()
+}
+
+/// Whether a Beta Code or Unicode word is in the [STOPWORDS].
+///
+/// ```
+/// use betacode::stemmer::is_stopword;
+/// assert!(is_stopword("kai\\"));
+/// assert!(is_stopword("Τῶν"));
+/// assert!(!is_stopword("λόγος"));
+/// ```
+pub fn is_stopword<T: Into<String>>(word: T) -> bool {
+    STOPWORDS.contains(&fold(word).as_str())
+}
+#[cfg(test)]
+mod test;
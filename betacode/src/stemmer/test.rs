@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn nominal_endings() {
+    for word in [
+        "λόγος",
+        "λόγου",
+        "λόγῳ",
+        "λόγον",
+        "λόγοι",
+        "λόγων",
+        "λόγοις",
+        "λόγους",
+    ] {
+        assert_eq!(stem(word), "λογ");
+    }
+    assert_eq!(stem("*yuxh/"), "ψυχ");
+    assert_eq!(stem("ψυχαῖς"), "ψυχ");
+}
+#[test]
+fn verbal_endings() {
+    assert_eq!(stem("παιδεύομεν"), "παιδευ");
+    assert_eq!(stem("paideu/ousi"), "παιδευ");
+    assert_eq!(stem("παιδεύεσθαι"), "παιδευ");
+    let options = StemOptions {
+        aggressiveness: Aggressiveness::Light,
+    };
+    assert_eq!(stem_with("παιδεύομεν", &options), "παιδευομεν");
+}
+#[test]
+fn aggressive() {
+    let options = StemOptions {
+        aggressiveness: Aggressiveness::Aggressive,
+    };
+    assert_eq!(stem_with("ἐπαίδευσα", &options), "παιδευ");
+    assert_eq!(stem_with("ἐπαιδεύθησαν", &options), "παιδευ");
+    assert_eq!(stem_with("ἔχομεν", &options), "εχ");
+    assert_eq!(stem_with("ὁ", &options), "ο");
+    assert_eq!(stem_with("ἐ", &options), "ε");
+    assert_eq!(stem_with("", &options), "");
+}
+#[test]
+fn stopwords() {
+    let words: Vec<&str> = "καὶ τὸν λόγον οὐκ ἔχει"
+        .split(' ')
+        .filter(|w| !is_stopword(*w))
+        .collect();
+    assert_eq!(words, vec!["λόγον", "ἔχει"]);
+}
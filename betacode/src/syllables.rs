@@ -0,0 +1,101 @@
+use crate::greek::{self, Letter};
+use std::ops::Range;
+
+/// Soft hyphen (U+00AD) inserted by [hyphenate] at the syllable boundaries.
+pub const SOFT_HYPHEN: char = '\u{00ad}';
+
+/// Consonant clusters (besides single consonants) that may begin a Greek word,
+/// and therefore stay together at the beginning of a syllable.
+const ONSETS: [&str; 49] = [
+    "βδ", "βλ", "βρ", "γλ", "γν", "γρ", "δμ", "δν", "δρ", "θλ", "θν", "θρ", "κλ", "κμ", "κν", "κρ",
+    "κτ", "μν", "πλ", "πν", "πρ", "πτ", "σβ", "σθ", "σκ", "σμ", "σπ", "στ", "σφ", "σχ", "τλ", "τμ",
+    "τρ", "φθ", "φλ", "φν", "φρ", "χθ", "χλ", "χμ", "χν", "χρ", "σκλ", "σκρ", "σπλ", "σπρ", "στρ",
+    "σφρ", "σχρ",
+];
+
+pub(crate) fn is_onset(cluster: &[Letter]) -> bool {
+    match cluster.len() {
+        0 | 1 => true,
+        _ => {
+            let cluster: String = cluster.iter().map(|l| l.lower()).collect();
+            ONSETS.contains(&cluster.as_str())
+        }
+    }
+}
+
+/// Splits the letters of a single word into syllables, returning the index
+/// ranges of each syllable.
+///
+/// Consonants between two nuclei go with the following syllable as long as they
+/// can begin a word; otherwise the first of them closes the preceding syllable.
+pub(crate) fn syllable_bounds(word: &[Letter]) -> Vec<Range<usize>> {
+    let nuclei = greek::nuclei(word);
+    let mut output = vec![];
+    let mut start = 0;
+    for pair in nuclei.windows(2) {
+        let cluster = &word[pair[0].end..pair[1].start];
+        let split = (0..=cluster.len())
+            .find(|i| is_onset(&cluster[*i..]))
+            .unwrap_or(cluster.len());
+        let end = pair[0].end + split;
+        output.push(start..end);
+        start = end;
+    }
+    output.push(start..word.len());
+    output
+}
+
+/// Splits a word into syllables following the Ancient Greek rules.
+///
+/// Consonant clusters that can begin a word stay together, diphthongs are kept
+/// in a single syllable and a diaeresis marks the separation of vowels.
+///
+/// ```
+/// let result = betacode::syllables::syllabify("ἄνθρωπος");
+/// assert_eq!(result, vec!["ἄν", "θρω", "πος"]);
+/// let result = betacode::syllables::syllabify("*phlhi+a/dew");
+/// assert_eq!(result, vec!["Πη", "λη", "ϊ", "ά", "δε", "ω"]);
+/// ```
+pub fn syllabify<T: Into<String>>(word: T) -> Vec<String> {
+    let word = greek::letters(greek::to_unicode(word));
+    syllable_bounds(&word)
+        .into_iter()
+        .map(|s| greek::render(&word[s]))
+        .collect()
+}
+
+/// Inserts soft hyphens (U+00AD) between the syllables of every Greek word of the text.
+///
+/// ```
+/// let result = betacode::syllables::hyphenate("μῆνιν ἄειδε");
+/// assert_eq!(result, "μῆ\u{00ad}νιν ἄ\u{00ad}ει\u{00ad}δε");
+/// ```
+pub fn hyphenate<T: Into<String>>(input: T) -> String {
+    let letters = greek::letters(greek::to_unicode(input));
+    let mut output: Vec<Letter> = vec![];
+    let mut i = 0;
+    while i < letters.len() {
+        if !letters[i].is_greek() {
+            output.push(letters[i].clone());
+            i += 1;
+            continue;
+        }
+        let end = (i..letters.len())
+            .find(|j| !letters[*j].is_greek())
+            .unwrap_or(letters.len());
+        let word = &letters[i..end];
+        for (n, syllable) in syllable_bounds(word).into_iter().enumerate() {
+            if n > 0 {
+                output.push(Letter {
+                    base: SOFT_HYPHEN,
+                    marks: vec![],
+                });
+            }
+            output.extend_from_slice(&word[syllable]);
+        }
+        i = end;
+    }
+    greek::render(&output)
+}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,26 @@
+use super::*;
+#[test]
+fn consonant_clusters() {
+    assert_eq!(syllabify("λόγος"), vec!["λό", "γος"]);
+    assert_eq!(syllabify("θάλασσα"), vec!["θά", "λασ", "σα"]);
+    assert_eq!(syllabify("ἄστρον"), vec!["ἄ", "στρον"]);
+    assert_eq!(syllabify("ἐκτός"), vec!["ἐ", "κτός"]);
+    assert_eq!(syllabify("ἐχθρός"), vec!["ἐχ", "θρός"]);
+    assert_eq!(syllabify("πρᾶγμα"), vec!["πρᾶγ", "μα"]);
+}
+#[test]
+fn diphthongs_and_diaeresis() {
+    assert_eq!(syllabify("οὐρανός"), vec!["οὐ", "ρα", "νός"]);
+    assert_eq!(syllabify("ἄειδε"), vec!["ἄ", "ει", "δε"]);
+    assert_eq!(syllabify("ἀΐσσω"), vec!["ἀ", "ΐσ", "σω"]);
+    assert_eq!(syllabify("ᾠδή"), vec!["ᾠ", "δή"]);
+}
+#[test]
+fn betacode_input() {
+    assert_eq!(syllabify("*a)xilh=os"), vec!["Ἀ", "χι", "λῆ", "ος"]);
+}
+#[test]
+fn soft_hyphens() {
+    assert_eq!(hyphenate("λόγος, ὦ"), "λό\u{00ad}γος, ὦ");
+    assert_eq!(hyphenate("lo/gos"), "λό\u{00ad}γος");
+}
@@ -0,0 +1,213 @@
+use crate::converter::{convert, BETA_MID_VALUES, UNI_VALUES};
+use crate::greek;
+use crate::numerals::parse_greek_numeral;
+use std::ops::Range;
+
+/// Characters opening a Beta Code markup code (`[1`, `$`, `&`, `@1`, ...).
+const MARKUP: &str = "$&@^[]<>{}";
+
+/// Kinds of [Token].
+///
+/// - [TokenKind::Word]: a Greek word, with its diacritics and elision mark.
+/// - [TokenKind::Punct]: a punctuation mark.
+/// - [TokenKind::Space]: a run of whitespace.
+/// - [TokenKind::Number]: an Arabic number or a Greek numeral (`kg#`, `#22a`).
+/// - [TokenKind::Markup]: a Beta Code markup code, with its number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Word,
+    Punct,
+    Space,
+    Number,
+    Markup,
+}
+
+/// A token of a Beta Code text.
+///
+/// `beta_span` is the byte range of the token in the Beta Code source, and
+/// `greek_text` its Unicode conversion (the source text for spaces and markup).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub beta_span: Range<usize>,
+    pub greek_text: String,
+}
+
+/// Beta Code units recognized with the converter tables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Unit {
+    Letter,
+    Mark,
+    Keraia,
+    LowerKeraia,
+    Other,
+}
+
+/// Finds the longest Beta Code unit at the beginning of the input.
+fn unit_at(input: &str) -> (usize, Unit) {
+    if let Some(rest) = input.strip_prefix('*') {
+        let marks = rest.find(|c| !")(/\\=+|".contains(c)).unwrap_or(rest.len());
+        return match unit_at(&rest[marks..]) {
+            (len, Unit::Letter) => (1 + marks + len, Unit::Letter),
+            _ => (1, Unit::Other),
+        };
+    }
+    let code = BETA_MID_VALUES
+        .iter()
+        .zip(UNI_VALUES.iter())
+        .filter(|(b, _)| input.starts_with(**b) && !b.starts_with('*'))
+        .max_by_key(|(b, _)| b.len());
+    match code {
+        Some((&"#22", _)) => (3, Unit::LowerKeraia),
+        Some((&"#", _)) => (1, Unit::Keraia),
+        Some((b, u)) => {
+            let c = u.chars().next().unwrap_or(' ');
+            let unit = match (greek::is_combining(c), greek::is_greek_char(c)) {
+                (true, _) => Unit::Mark,
+                (false, true) => Unit::Letter,
+                _ => Unit::Other,
+            };
+            (b.len(), unit)
+        }
+        None => (input.chars().next().map_or(1, char::len_utf8), Unit::Other),
+    }
+}
+
+/// Length of the run of letters and diacritics (and sigma variants) at the beginning of the input.
+fn letters_len(input: &str) -> usize {
+    let mut i = 0;
+    let mut sigma = false;
+    while i < input.len() {
+        let rest = &input[i..];
+        if sigma && rest.starts_with(['1', '2', '3']) {
+            i += 1;
+            sigma = false;
+            continue;
+        }
+        match unit_at(rest) {
+            (len, Unit::Letter) => {
+                sigma = rest.trim_start_matches('*').starts_with(['s', 'S']);
+                i += len;
+            }
+            (len, Unit::Mark) => i += len,
+            _ => break,
+        }
+    }
+    i
+}
+
+/// Splits a Beta Code text into units of a single letter with its diacritics
+/// (or of any other single code or character), returning their byte ranges.
+pub(crate) fn letter_units(input: &str) -> Vec<Range<usize>> {
+    let mut output = vec![];
+    let mut i = 0;
+    while i < input.len() {
+        let len = match unit_at(&input[i..]) {
+            (len, Unit::Letter) => len + marks_len(&input[i..]),
+            (len, _) => len,
+        };
+        output.push(i..i + len);
+        i += len;
+    }
+    output
+}
+
+/// Length of the diacritics (and sigma variant) following the letter at the beginning of the input.
+fn marks_len(input: &str) -> usize {
+    let (len, _) = unit_at(input);
+    let sigma = input.trim_start_matches('*').starts_with(['s', 'S']);
+    let mut i = len;
+    loop {
+        let rest = &input[i..];
+        if sigma && rest.starts_with(['1', '2', '3']) {
+            return i + 1 - len;
+        }
+        match unit_at(rest) {
+            (mark, Unit::Mark) if !rest.is_empty() => i += mark,
+            _ => return i - len,
+        }
+    }
+}
+
+/// Splits a Beta Code text into words, punctuation, spaces, numbers and markup.
+///
+/// Each token keeps its span in the Beta Code source and its Unicode conversion.
+/// Diacritics, sigma variants and the elision mark stay with their word, and letters
+/// followed by a keraia (or preceded by a lower keraia) are read as Greek numerals.
+///
+/// ```
+/// use betacode::tokenizer::{tokenize, TokenKind};
+/// let tokens = tokenize("*)a/nqrwpos, d' e)/tos kg#");
+/// assert_eq!(tokens[0].kind, TokenKind::Word);
+/// assert_eq!(tokens[0].beta_span, 0..11);
+/// assert_eq!(tokens[0].greek_text, "Ἄνθρωπος");
+/// assert_eq!(tokens[1].kind, TokenKind::Punct);
+/// assert_eq!(tokens[3].greek_text, "δ’");
+/// assert_eq!(tokens[7].kind, TokenKind::Number);
+/// assert_eq!(tokens[7].greek_text, "κγʹ");
+/// ```
+pub fn tokenize<T: Into<String>>(input: T) -> Vec<Token> {
+    let input: String = input.into();
+    let mut output = vec![];
+    let mut i = 0;
+    while i < input.len() {
+        let rest = &input[i..];
+        let c = rest.chars().next().unwrap_or(' ');
+        let (len, kind) = if c.is_whitespace() {
+            let len = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+            (len, TokenKind::Space)
+        } else if MARKUP.contains(c) {
+            let digits = rest[1..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len() - 1);
+            (1 + digits, TokenKind::Markup)
+        } else if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            (len, TokenKind::Number)
+        } else {
+            match unit_at(rest) {
+                (_, Unit::Letter) => {
+                    let mut len = letters_len(rest);
+                    let after = &rest[len..];
+                    if after.starts_with('\'') {
+                        (len + 1, TokenKind::Word)
+                    } else if after.starts_with('#') && !after.starts_with("#22") {
+                        len += 1;
+                        match parse_greek_numeral(&rest[..len]) {
+                            Ok(_) => (len, TokenKind::Number),
+                            Err(_) => (len - 1, TokenKind::Word),
+                        }
+                    } else {
+                        (len, TokenKind::Word)
+                    }
+                }
+                (sign, Unit::LowerKeraia) => {
+                    let mut len = sign + letters_len(&rest[sign..]);
+                    if rest[len..].starts_with('#') && !rest[len..].starts_with("#22") {
+                        len += 1;
+                    }
+                    (len, TokenKind::Number)
+                }
+                (len, _) => (len, TokenKind::Punct),
+            }
+        };
+        let text = &input[i..i + len];
+        let greek_text = match kind {
+            TokenKind::Space | TokenKind::Markup => text.to_string(),
+            _ => convert(text),
+        };
+        output.push(Token {
+            kind,
+            beta_span: i..i + len,
+            greek_text,
+        });
+        i += len;
+    }
+    output
+}
+#[cfg(test)]
+mod test;
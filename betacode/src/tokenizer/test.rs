@@ -0,0 +1,71 @@
+use super::*;
+fn kinds(input: &str) -> Vec<TokenKind> {
+    tokenize(input).iter().map(|t| t.kind).collect()
+}
+#[test]
+fn words_and_punctuation() {
+    let tokens = tokenize("mh=nin a)/eide, qea/:");
+    let words: Vec<&str> = tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Word)
+        .map(|t| t.greek_text.as_str())
+        .collect();
+    assert_eq!(words, vec!["μῆνιν", "ἄειδε", "θεά"]);
+    assert_eq!(tokens.last().unwrap().greek_text, "·");
+    assert_eq!(tokens[2].beta_span, 7..14);
+}
+#[test]
+fn sigmas_and_special_letters() {
+    let tokens = tokenize("lo/gos3 s1a #3a");
+    assert_eq!(tokens[0].beta_span, 0..7);
+    assert_eq!(tokens[0].greek_text, "λόγοϲ");
+    assert_eq!(tokens[2].greek_text, "ςα");
+    assert_eq!(tokens[4].greek_text, "ϙα");
+}
+#[test]
+fn numbers_and_markup() {
+    assert_eq!(
+        kinds("[1 #22a 12 a)/ra]1"),
+        vec![
+            TokenKind::Markup,
+            TokenKind::Space,
+            TokenKind::Number,
+            TokenKind::Space,
+            TokenKind::Number,
+            TokenKind::Space,
+            TokenKind::Word,
+            TokenKind::Markup,
+        ]
+    );
+}
+#[test]
+fn spans_cover_input() {
+    let input = "*)axilh=os d' a)/ra, kg# [2";
+    let tokens = tokenize(input);
+    let rebuilt: String = tokens.iter().map(|t| &input[t.beta_span.clone()]).collect();
+    assert_eq!(rebuilt, input);
+}
+#[test]
+fn elided_words() {
+    let tokens = tokenize("a)ll' e)/fas'");
+    assert_eq!(tokens[0].beta_span, 0..5);
+    assert_eq!(tokens[0].greek_text, "ἀλλ’");
+    assert_eq!(tokens[2].greek_text, "ἔφασ’");
+}
+#[test]
+fn dangling_asterisk() {
+    assert_eq!(
+        kinds("a * b"),
+        vec![
+            TokenKind::Word,
+            TokenKind::Space,
+            TokenKind::Punct,
+            TokenKind::Space,
+            TokenKind::Word,
+        ]
+    );
+    let tokens = tokenize("lo/gos*");
+    assert_eq!(tokens[0].greek_text, "λόγος");
+    assert_eq!(tokens[1].greek_text, "*");
+    assert_eq!(tokenize("*")[0].greek_text, "*");
+}
@@ -0,0 +1,629 @@
+use crate::converter::{compose_unicode, convert};
+use crate::folding::fold;
+use crate::greek::{self, Letter, DIAERESIS, IOTA_SUBSCRIPT, ROUGH};
+use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
+
+/// Conventional Latin and English forms that do not follow the regular rules of [latinize].
+const LATIN_EXCEPTIONS: [(&str, &str); 8] = [
+    ("αχιλλευσ", "Achilles"),
+    ("αχιλευσ", "Achilles"),
+    ("αιασ", "Ajax"),
+    ("ηρακλησ", "Hercules"),
+    ("κλυταιμνηστρα", "Clytemnestra"),
+    ("πλατων", "Plato"),
+    ("ζηνων", "Zeno"),
+    ("απολλων", "Apollo"),
+];
+
+/// Greek name endings and their Latin counterparts, checked in order.
+const LATIN_ENDINGS: [(&str, &str); 3] = [("ρος", "er"), ("ος", "us"), ("ον", "um")];
+
+/// Romanization schemes supported by [transliterate].
+///
+/// - [Scheme::AlaLc]: ALA-LC romanization of Ancient Greek (`η` → `ē`, `ᾳ` → `ai`).
+/// - [Scheme::Sbl]: SBL Handbook of Style academic transliteration (`ᾳ` → `ą`).
+/// - [Scheme::Iso843]: ISO 843 transliteration (`η` → `ī`, `β` → `v`), keeping accents as acutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    AlaLc,
+    Sbl,
+    Iso843,
+}
+
+const MACRON: &str = "\u{0304}";
+const ACUTE: &str = "\u{0301}";
+const OGONEK: &str = "\u{0328}";
+
+fn vowel(c: char, scheme: Scheme, in_diphthong: bool) -> String {
+    match (c, scheme) {
+        ('α', _) => "a".to_string(),
+        ('ε', _) => "e".to_string(),
+        ('η', Scheme::Iso843) => format!("i{MACRON}"),
+        ('η', _) => format!("e{MACRON}"),
+        ('ι', _) => "i".to_string(),
+        ('ο', _) => "o".to_string(),
+        ('υ', _) if in_diphthong => "u".to_string(),
+        ('υ', _) => "y".to_string(),
+        ('ω', _) => format!("o{MACRON}"),
+        _ => c.to_string(),
+    }
+}
+
+fn iota_subscript(c: char, scheme: Scheme) -> String {
+    match scheme {
+        Scheme::Sbl => match c {
+            'α' => format!("a{OGONEK}"),
+            'η' => format!("e{OGONEK}{MACRON}"),
+            _ => format!("o{OGONEK}{MACRON}"),
+        },
+        _ => format!("{}i", vowel(c, scheme, false)),
+    }
+}
+
+fn consonant(word: &[Letter], i: usize, scheme: Scheme) -> String {
+    let next = word.get(i + 1).map(|l| l.lower());
+    let previous = i.checked_sub(1).map(|p| word[p].lower());
+    match (word[i].lower(), scheme) {
+        ('β', Scheme::Iso843) => "v".to_string(),
+        ('β', _) => "b".to_string(),
+        ('γ', Scheme::Iso843) => "g".to_string(),
+        ('γ', _) if matches!(next, Some('γ' | 'κ' | 'ξ' | 'χ')) => "n".to_string(),
+        ('γ', _) => "g".to_string(),
+        ('δ', _) => "d".to_string(),
+        ('ζ', _) => "z".to_string(),
+        ('θ', _) => "th".to_string(),
+        ('κ', _) => "k".to_string(),
+        ('λ', _) => "l".to_string(),
+        ('μ', _) => "m".to_string(),
+        ('ν', _) => "n".to_string(),
+        ('ξ', _) => "x".to_string(),
+        ('π', _) => "p".to_string(),
+        ('ρ', _) if word[i].has(ROUGH) || previous == Some('ρ') => "rh".to_string(),
+        ('ρ', _) => "r".to_string(),
+        ('σ', _) => "s".to_string(),
+        ('τ', _) => "t".to_string(),
+        ('φ', Scheme::Iso843) => "f".to_string(),
+        ('φ', _) => "ph".to_string(),
+        ('χ', _) => "ch".to_string(),
+        ('ψ', _) => "ps".to_string(),
+        ('ϝ', _) => "w".to_string(),
+        ('ϛ', _) => "st".to_string(),
+        ('ϟ' | 'ϙ', _) => "q".to_string(),
+        ('ϡ', _) => "ss".to_string(),
+        (c, _) => c.to_string(),
+    }
+}
+
+fn punctuation(c: char) -> char {
+    match c {
+        ';' => '?',
+        '·' => ';',
+        _ => c,
+    }
+}
+
+fn transliterate_word(word: &[Letter], scheme: Scheme) -> String {
+    let nuclei = greek::nuclei(word);
+    let all_caps = word.len() > 1 && word.iter().all(|l| l.is_upper());
+    let mut output = String::new();
+    for (i, letter) in word.iter().enumerate() {
+        let nucleus = nuclei.iter().find(|n| n.contains(&i));
+        let mut segment = match nucleus {
+            Some(n) => {
+                let mut segment = String::new();
+                if n.start == i && word[n.clone()].iter().any(|l| l.has(ROUGH)) {
+                    segment.push('h');
+                }
+                // ISO 843 writes `u` only in αυ, ευ and ου
+                let in_diphthong = match scheme {
+                    Scheme::Iso843 => {
+                        n.len() > 1
+                            && n.start < i
+                            && matches!(word[n.start].lower(), 'α' | 'ε' | 'ο')
+                    }
+                    _ => n.len() > 1,
+                };
+                match letter.has(IOTA_SUBSCRIPT) {
+                    true => segment.push_str(&iota_subscript(letter.lower(), scheme)),
+                    false => segment.push_str(&vowel(letter.lower(), scheme, in_diphthong)),
+                }
+                if letter.has(DIAERESIS) {
+                    segment.push(DIAERESIS);
+                }
+                if scheme == Scheme::Iso843 && letter.accent().is_some() {
+                    segment.push_str(ACUTE);
+                }
+                segment
+            }
+            None => consonant(word, i, scheme),
+        };
+        if letter.is_upper() {
+            segment = match all_caps {
+                true => segment.to_uppercase(),
+                false => capitalize(&segment),
+            };
+        }
+        output.push_str(&segment);
+    }
+    output
+}
+
+pub(crate) fn capitalize(input: &str) -> String {
+    let mut chars = input.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Romanizes a Beta Code or Unicode text into Latin script following the given scheme.
+///
+/// The rough breathing is rendered as `h` (`rh` on `ρ`), `γ` before a velar as `n`
+/// (`g` in ISO 843), `υ` as `u` within diphthongs (only `αυ`, `ευ` and `ου` in ISO 843)
+/// and the iota subscript as the scheme prescribes.
+///
+/// ```
+/// use betacode::transliteration::{transliterate, Scheme};
+/// assert_eq!(transliterate("ἀρετή", Scheme::AlaLc), "aretē");
+/// assert_eq!(transliterate("r(h/twr", Scheme::Sbl), "rhētōr");
+/// assert_eq!(transliterate("ἄγγελος", Scheme::AlaLc), "angelos");
+/// assert_eq!(transliterate("φιλοσοφία", Scheme::Iso843), "filosofía");
+/// ```
+pub fn transliterate<T: Into<String>>(input: T, scheme: Scheme) -> String {
+    let letters = greek::letters(greek::to_unicode(input));
+    let mut output = String::new();
+    let mut i = 0;
+    while i < letters.len() {
+        if !letters[i].is_greek() {
+            output.push(punctuation(letters[i].base));
+            i += 1;
+            continue;
+        }
+        let end = (i..letters.len())
+            .find(|j| !letters[*j].is_greek())
+            .unwrap_or(letters.len());
+        output.push_str(&transliterate_word(&letters[i..end], scheme));
+        i = end;
+    }
+    compose_unicode(output)
+}
+/// A romanized character (or sequence) whose Greek value had to be guessed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guess {
+    /// Byte offset of the romanized sequence in the input.
+    pub position: usize,
+    /// The romanized sequence.
+    pub romanized: String,
+    /// The Beta Code chosen for it (empty if it was dropped).
+    pub chosen: String,
+}
+
+/// Result of [detransliterate].
+#[derive(Debug, Clone)]
+pub struct Detransliteration {
+    pub betacode: String,
+    pub greek: String,
+    pub guesses: Vec<Guess>,
+}
+
+const LATIN_DIPHTHONGS: [&str; 8] = ["ai", "ei", "oi", "ui", "au", "eu", "ou", "hu"];
+
+#[derive(Debug)]
+enum Unit {
+    Consonant(String),
+    Vowel {
+        beta: char,
+        breathing: Option<char>,
+        accent: Option<char>,
+        diaeresis: bool,
+        subscript: bool,
+    },
+}
+
+/// A Latin letter with the combining marks of its decomposition.
+struct Latin {
+    position: usize,
+    base: char,
+    marks: Vec<char>,
+}
+
+fn latin_letters(word: &str, offset: usize) -> Vec<Latin> {
+    let mut output: Vec<Latin> = vec![];
+    for (i, c) in word.char_indices() {
+        let mut decomposed = c.to_string().nfd().collect::<Vec<char>>().into_iter();
+        let base = decomposed.next().unwrap_or(c);
+        match (greek::is_combining(base), output.last_mut()) {
+            (true, Some(latin)) => latin.marks.push(base),
+            _ => output.push(Latin {
+                position: offset + i,
+                base: base.to_lowercase().next().unwrap_or(base),
+                marks: vec![],
+            }),
+        }
+        if let Some(latin) = output.last_mut() {
+            latin.marks.extend(decomposed);
+        }
+    }
+    output
+}
+
+fn vowel_unit(beta: char, marks: &[char]) -> Unit {
+    let accent = marks.iter().find_map(|m| match m {
+        '\u{0301}' => Some('/'),
+        '\u{0300}' => Some('\\'),
+        '\u{0302}' | '\u{0342}' => Some('='),
+        _ => None,
+    });
+    Unit::Vowel {
+        beta,
+        breathing: None,
+        accent,
+        diaeresis: marks.contains(&'\u{0308}'),
+        subscript: marks.contains(&'\u{0328}'),
+    }
+}
+
+fn detransliterate_word(
+    word: &str,
+    offset: usize,
+    scheme: Scheme,
+    guesses: &mut Vec<Guess>,
+) -> String {
+    let letters = latin_letters(word, offset);
+    let next = |i: usize| letters.get(i + 1).map(|l| l.base);
+    let mut guess = |l: &Latin, chosen: &str| {
+        guesses.push(Guess {
+            position: l.position,
+            romanized: l.base.to_string(),
+            chosen: chosen.to_string(),
+        })
+    };
+    let mut units: Vec<Unit> = vec![];
+    let mut rough = false;
+    let mut i = 0;
+    while i < letters.len() {
+        let l = &letters[i];
+        let long = l.marks.contains(&'\u{0304}');
+        let (unit, width) = match l.base {
+            'h' if i == 0 => {
+                rough = true;
+                i += 1;
+                continue;
+            }
+            't' if next(i) == Some('h') => (Unit::Consonant("q".to_string()), 2),
+            'p' if next(i) == Some('h') => (Unit::Consonant("f".to_string()), 2),
+            'p' if next(i) == Some('s') => (Unit::Consonant("y".to_string()), 2),
+            'c' | 'k' if next(i) == Some('h') => (Unit::Consonant("x".to_string()), 2),
+            'r' if next(i) == Some('h') && i == 0 => (Unit::Consonant("r(".to_string()), 2),
+            'r' if next(i) == Some('h') => (Unit::Consonant("r".to_string()), 2),
+            // ISO 843 writes every γ as g, so its n is always a ν.
+            'n' if scheme != Scheme::Iso843 && matches!(next(i), Some('g' | 'k' | 'x' | 'c')) => {
+                (Unit::Consonant("g".to_string()), 1)
+            }
+            'b' | 'g' | 'd' | 'z' | 'k' | 'l' | 'm' | 'n' | 'p' | 'r' | 's' | 't' => {
+                (Unit::Consonant(l.base.to_string()), 1)
+            }
+            'x' => (Unit::Consonant("c".to_string()), 1),
+            'v' if scheme == Scheme::Iso843 => (Unit::Consonant("b".to_string()), 1),
+            'f' if scheme == Scheme::Iso843 => (Unit::Consonant("f".to_string()), 1),
+            'v' => {
+                guess(l, "b");
+                (Unit::Consonant("b".to_string()), 1)
+            }
+            'f' => {
+                guess(l, "f");
+                (Unit::Consonant("f".to_string()), 1)
+            }
+            'c' | 'q' => {
+                guess(l, "k");
+                (Unit::Consonant("k".to_string()), 1)
+            }
+            'w' => {
+                guess(l, "v");
+                (Unit::Consonant("v".to_string()), 1)
+            }
+            'a' => (vowel_unit('a', &l.marks), 1),
+            'e' if long && scheme != Scheme::Iso843 => (vowel_unit('h', &l.marks), 1),
+            'e' => (vowel_unit('e', &l.marks), 1),
+            'i' if long && scheme == Scheme::Iso843 => (vowel_unit('h', &l.marks), 1),
+            'i' | 'j' => (vowel_unit('i', &l.marks), 1),
+            'o' if long => (vowel_unit('w', &l.marks), 1),
+            'o' => (vowel_unit('o', &l.marks), 1),
+            'y' | 'u' => (vowel_unit('u', &l.marks), 1),
+            _ => {
+                guess(l, "");
+                i += 1;
+                continue;
+            }
+        };
+        units.push(unit);
+        i += width;
+    }
+    // An initial h not followed by a vowel has no breathing to become.
+    if rough && !matches!(units.first(), Some(Unit::Vowel { .. })) {
+        guess(&letters[0], "");
+    }
+
+    // ALA-LC and ISO 843 write the iota subscript as a following i.
+    if scheme != Scheme::Sbl {
+        let mut j = 0;
+        while j + 1 < units.len() {
+            if let (
+                Unit::Vowel {
+                    beta: 'h' | 'w', ..
+                },
+                Unit::Vowel { beta: 'i', .. },
+            ) = (&units[j], &units[j + 1])
+            {
+                units.remove(j + 1);
+                if let Unit::Vowel { subscript, .. } = &mut units[j] {
+                    *subscript = true;
+                }
+            }
+            j += 1;
+        }
+    }
+
+    let diphthong = |a: &Unit, b: &Unit| match (a, b) {
+        (
+            Unit::Vowel { beta: x, .. },
+            Unit::Vowel {
+                beta: y,
+                diaeresis: false,
+                ..
+            },
+        ) => LATIN_DIPHTHONGS.contains(&format!("{x}{y}").as_str()),
+        _ => false,
+    };
+    let mut in_diphthong = vec![false; units.len()];
+    let mut j = 0;
+    while j + 1 < units.len() {
+        if diphthong(&units[j], &units[j + 1]) {
+            in_diphthong[j] = true;
+            in_diphthong[j + 1] = true;
+            j += 2;
+        } else {
+            j += 1;
+        }
+    }
+    let letters_u: Vec<&Latin> = letters.iter().filter(|l| "uy".contains(l.base)).collect();
+    let mut u = 0;
+    for (j, unit) in units.iter().enumerate() {
+        if let Unit::Vowel { beta: 'u', .. } = unit {
+            if let Some(l) = letters_u.get(u) {
+                if l.base == 'u' && !in_diphthong[j] {
+                    guesses.push(Guess {
+                        position: l.position,
+                        romanized: "u".to_string(),
+                        chosen: "u".to_string(),
+                    });
+                }
+            }
+            u += 1;
+        }
+    }
+
+    if let Some(Unit::Vowel { .. }) = units.first() {
+        let target = match in_diphthong.get(1) {
+            Some(true) => 1,
+            _ => 0,
+        };
+        if let Unit::Vowel { breathing, .. } = &mut units[target] {
+            *breathing = Some(if rough { '(' } else { ')' });
+        }
+    }
+
+    let capital = word.chars().next().is_some_and(char::is_uppercase);
+    let mut output = String::new();
+    for (j, unit) in units.iter().enumerate() {
+        match unit {
+            Unit::Consonant(c) => output.push_str(c),
+            Unit::Vowel {
+                beta,
+                breathing,
+                accent,
+                diaeresis,
+                subscript,
+            } => {
+                let mut marks = String::new();
+                breathing.iter().for_each(|b| marks.push(*b));
+                if *diaeresis {
+                    marks.push('+');
+                }
+                accent.iter().for_each(|a| marks.push(*a));
+                if capital && j == 0 && !in_diphthong[0] {
+                    output.push_str(&marks);
+                    output.push(*beta);
+                } else {
+                    output.push(*beta);
+                    output.push_str(&marks);
+                }
+                if *subscript {
+                    output.push('|');
+                }
+            }
+        }
+    }
+    // A capitalized word with no Greek units (an initial, a Greek word) gets no `*`
+    match capital && !output.is_empty() {
+        true => format!("*{output}"),
+        false => output,
+    }
+}
+
+/// Parses a text romanized with the given scheme back into Beta Code and Unicode Greek.
+///
+/// Vowel-length marks choose between `ε`/`η` and `ο`/`ω`, an initial `h` (or `rh`)
+/// gives the rough breathing, and every character that had no definite Greek
+/// value is reported in [Detransliteration::guesses], including an initial `h`
+/// with no vowel to carry it. In ISO 843, where `γ` is always `g`, `n` is always `ν`.
+///
+/// ```
+/// use betacode::transliteration::{detransliterate, Scheme};
+/// let result = detransliterate("anthrōpos", Scheme::AlaLc);
+/// assert_eq!(result.betacode, "a)nqrwpos");
+/// assert_eq!(result.greek, "ἀνθρωπος");
+/// assert!(result.guesses.is_empty());
+///
+/// let result = detransliterate("Homēros", Scheme::AlaLc);
+/// assert_eq!(result.greek, "Ὁμηρος");
+///
+/// let result = detransliterate("Cyclops", Scheme::AlaLc);
+/// assert_eq!(result.greek, "Κυκλοψ");
+/// assert_eq!(result.guesses.len(), 2);
+/// ```
+pub fn detransliterate<T: Into<String>>(input: T, scheme: Scheme) -> Detransliteration {
+    let input: String = input.into();
+    let mut guesses = vec![];
+    let mut betacode = String::new();
+    let mut word_start: Option<usize> = None;
+    for (i, c) in input.char_indices().chain([(input.len(), ' ')]) {
+        let in_word = i < input.len() && (c.is_alphabetic() || greek::is_combining(c));
+        match (in_word, word_start) {
+            (true, None) => word_start = Some(i),
+            (false, Some(start)) => {
+                betacode.push_str(&detransliterate_word(
+                    &input[start..i],
+                    start,
+                    scheme,
+                    &mut guesses,
+                ));
+                word_start = None;
+            }
+            _ => (),
+        }
+        if !in_word && i < input.len() {
+            betacode.push(match c {
+                '?' => ';',
+                ';' => ':',
+                _ => c,
+            });
+        }
+    }
+    let greek = convert(betacode.clone());
+    Detransliteration {
+        betacode,
+        greek,
+        guesses,
+    }
+}
+fn latinize_letters(word: &[Letter]) -> String {
+    let nuclei = greek::nuclei(word);
+    let mut output = String::new();
+    for (i, letter) in word.iter().enumerate() {
+        let next = word.get(i + 1).map(|l| l.lower());
+        let previous = i.checked_sub(1).map(|p| word[p].lower());
+        let segment = match nuclei.iter().find(|n| n.start == i) {
+            Some(n) => {
+                let rough = word[n.clone()].iter().any(|l| l.has(ROUGH));
+                let before_vowel = word.get(n.end).is_some_and(|l| l.is_vowel());
+                let vowel: String = word[n.clone()].iter().map(|l| l.lower()).collect();
+                let latin = match vowel.as_str() {
+                    "αι" => "ae",
+                    "οι" => "oe",
+                    "ει" if before_vowel => "e",
+                    "ει" => "i",
+                    "ου" => "u",
+                    "αυ" => "au",
+                    "ευ" | "ηυ" => "eu",
+                    "υι" => "yi",
+                    "α" => "a",
+                    "ε" | "η" => "e",
+                    "ι" => "i",
+                    "ο" | "ω" => "o",
+                    "υ" => "y",
+                    _ => "",
+                };
+                match rough {
+                    true => format!("h{latin}"),
+                    false => latin.to_string(),
+                }
+            }
+            None if letter.is_vowel() => String::new(),
+            None => match letter.lower() {
+                'γ' if matches!(next, Some('γ' | 'κ' | 'ξ' | 'χ')) => "n".to_string(),
+                'κ' => "c".to_string(),
+                'ρ' if letter.has(ROUGH) || previous == Some('ρ') => "rh".to_string(),
+                _ => consonant(word, i, Scheme::AlaLc),
+            },
+        };
+        output.push_str(&segment);
+    }
+    output
+}
+
+fn latinize_word(word: &[Letter], exceptions: &HashMap<String, String>) -> String {
+    let folded: String = fold(greek::render(word));
+    if let Some(latin) = exceptions.get(&folded) {
+        return latin.clone();
+    }
+    if let Some((_, latin)) = LATIN_EXCEPTIONS.iter().find(|(g, _)| *g == folded) {
+        return latin.to_string();
+    }
+    let ending = LATIN_ENDINGS.iter().find(|(g, _)| {
+        let len = g.chars().count();
+        folded.ends_with(&fold(*g))
+            && word.len() > len
+            && (*g != "ρος" || "βγδθκπτφχ".contains(word[word.len() - len - 1].lower()))
+    });
+    let latin = match ending {
+        Some((g, l)) => {
+            let stem = &word[..word.len() - g.chars().count()];
+            format!("{}{}", latinize_letters(stem), l)
+        }
+        None => latinize_letters(word),
+    };
+    capitalize(&latin)
+}
+
+/// Renders Greek proper names in their conventional Latinized form.
+///
+/// `κ` becomes `c`, `αι` and `οι` become `ae` and `oe`, `ου` becomes `u`, and the endings
+/// `-ος`, `-ον` and stop + `-ρος` become `-us`, `-um` and `-er`. Names with an irregular
+/// conventional form (Ἀχιλλεύς → Achilles, Πλάτων → Plato) are taken from a built-in list.
+///
+/// ```
+/// use betacode::transliteration::latinize;
+/// assert_eq!(latinize("Ἀχιλλεύς"), "Achilles");
+/// assert_eq!(latinize("*qoukudi/dhs"), "Thucydides");
+/// assert_eq!(latinize("Ἀλέξανδρος"), "Alexander");
+/// ```
+pub fn latinize<T: Into<String>>(input: T) -> String {
+    latinize_with(input, &HashMap::new())
+}
+
+/// Same as [latinize], looking every word up first in a user-supplied dictionary.
+///
+/// The keys may be written in Beta Code or Unicode, with or without diacritics.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use betacode::transliteration::latinize_with;
+/// let exceptions = HashMap::from([("*)odusseu/s".to_string(), "Ulysses".to_string())]);
+/// assert_eq!(latinize_with("Ὀδυσσεύς", &exceptions), "Ulysses");
+/// ```
+pub fn latinize_with<T: Into<String>>(input: T, exceptions: &HashMap<String, String>) -> String {
+    let exceptions: HashMap<String, String> = exceptions
+        .iter()
+        .map(|(k, v)| (fold(k.as_str()), v.clone()))
+        .collect();
+    let letters = greek::letters(greek::to_unicode(input));
+    let mut output = String::new();
+    let mut i = 0;
+    while i < letters.len() {
+        if !letters[i].is_greek() {
+            output.push(punctuation(letters[i].base));
+            i += 1;
+            continue;
+        }
+        let end = (i..letters.len())
+            .find(|j| !letters[*j].is_greek())
+            .unwrap_or(letters.len());
+        output.push_str(&latinize_word(&letters[i..end], &exceptions));
+        i = end;
+    }
+    compose_unicode(output)
+}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,142 @@
+use super::*;
+#[test]
+fn ala_lc() {
+    assert_eq!(transliterate("ἀρετή", Scheme::AlaLc), "aretē");
+    assert_eq!(transliterate("ῥήτωρ", Scheme::AlaLc), "rhētōr");
+    assert_eq!(transliterate("Ὅμηρος", Scheme::AlaLc), "Homēros");
+    assert_eq!(transliterate("οἱ υἱοί", Scheme::AlaLc), "hoi huioi");
+    assert_eq!(
+        transliterate("ἄγκυρα σφίγξ", Scheme::AlaLc),
+        "ankyra sphinx"
+    );
+    assert_eq!(transliterate("Πύρρος", Scheme::AlaLc), "Pyrrhos");
+    assert_eq!(transliterate("τῷ ᾠδῇ", Scheme::AlaLc), "tōi ōidēi");
+    assert_eq!(transliterate("τί; ναί·", Scheme::AlaLc), "ti? nai;");
+}
+#[test]
+fn sbl() {
+    assert_eq!(transliterate("τῷ ᾠδῇ", Scheme::Sbl), "tǭ ǭdę̄");
+    assert_eq!(transliterate("*a)xilleu/s", Scheme::Sbl), "Achilleus");
+    assert_eq!(transliterate("Πηληϊάδεω", Scheme::Sbl), "Pēlēïadeō");
+}
+#[test]
+fn iso_843() {
+    assert_eq!(transliterate("ἀρετή", Scheme::Iso843), "aretī́");
+    assert_eq!(transliterate("ἄγκυρα", Scheme::Iso843), "ágkyra");
+    assert_eq!(transliterate("βασιλεύς", Scheme::Iso843), "vasileús");
+    assert_eq!(transliterate("ἄγγελος", Scheme::Iso843), "ággelos");
+    assert_eq!(transliterate("σφίγξ", Scheme::Iso843), "sfígx");
+    assert_eq!(transliterate("ἔγχος", Scheme::Iso843), "égchos");
+    assert_eq!(transliterate("οὐρανός", Scheme::Iso843), "ouranós");
+    assert_eq!(transliterate("εὐαγγέλιον", Scheme::Iso843), "euaggélion");
+    assert_eq!(transliterate("αὐτός", Scheme::Iso843), "autós");
+    assert_eq!(transliterate("υἱός", Scheme::Iso843), "hyiós");
+}
+#[test]
+fn all_caps() {
+    assert_eq!(transliterate("ΘΕΟΣ", Scheme::AlaLc), "THEOS");
+    assert_eq!(transliterate("Θεός", Scheme::AlaLc), "Theos");
+}
+#[test]
+fn detransliterate_ala_lc() {
+    let result = detransliterate("aretē", Scheme::AlaLc);
+    assert_eq!(result.betacode, "a)reth");
+    assert_eq!(result.greek, "ἀρετη");
+    let result = detransliterate("rhētōr", Scheme::AlaLc);
+    assert_eq!(result.greek, "ῥητωρ");
+    let result = detransliterate("hoi huioi", Scheme::AlaLc);
+    assert_eq!(result.greek, "οἱ υἱοι");
+    let result = detransliterate("angelos tōi", Scheme::AlaLc);
+    assert_eq!(result.greek, "ἀγγελος τῳ");
+    let result = detransliterate("Aias Eudoxos", Scheme::AlaLc);
+    assert_eq!(result.greek, "Αἰας Εὐδοξος");
+    assert!(result.guesses.is_empty());
+}
+#[test]
+fn detransliterate_sbl_and_iso() {
+    let result = detransliterate("tǭ", Scheme::Sbl);
+    assert_eq!(result.greek, "τῳ");
+    let result = detransliterate("filosofía", Scheme::Iso843);
+    assert_eq!(result.greek, "φιλοσοφία");
+    let result = detransliterate("aretī́", Scheme::Iso843);
+    assert_eq!(result.greek, "ἀρετή");
+}
+#[test]
+fn iso_843_round_trip() {
+    for word in [
+        "ἄγγελος",
+        "σφίγξ",
+        "ἔγχος",
+        "ἄγκυρα",
+        "ἐνκαυστικός",
+        "εὐαγγέλιον",
+    ] {
+        let romanized = transliterate(word, Scheme::Iso843);
+        assert_eq!(detransliterate(romanized, Scheme::Iso843).greek, word);
+    }
+    assert_eq!(detransliterate("ankyra", Scheme::AlaLc).greek, "ἀγκυρα");
+}
+#[test]
+fn detransliterate_guesses() {
+    let result = detransliterate("Phoebus", Scheme::AlaLc);
+    assert_eq!(result.greek, "Φοεβυς");
+    assert_eq!(
+        result.guesses,
+        vec![Guess {
+            position: 5,
+            romanized: "u".to_string(),
+            chosen: "u".to_string()
+        }]
+    );
+}
+#[test]
+fn detransliterate_without_greek_units() {
+    let result = detransliterate("H", Scheme::AlaLc);
+    assert_eq!(result.betacode, "");
+    assert_eq!(
+        result.guesses,
+        vec![Guess {
+            position: 0,
+            romanized: "h".to_string(),
+            chosen: "".to_string()
+        }]
+    );
+    let result = detransliterate("Hm", Scheme::AlaLc);
+    assert_eq!(result.betacode, "*m");
+    assert_eq!(result.guesses.len(), 1);
+    assert_eq!(
+        detransliterate("H. Smyth", Scheme::AlaLc).betacode,
+        ". *smuq"
+    );
+    let result = detransliterate("ΑΙ", Scheme::AlaLc);
+    assert_eq!(result.betacode, "");
+    assert_eq!(result.guesses.len(), 2);
+}
+#[test]
+fn latinized_names() {
+    assert_eq!(latinize("Κλυταιμνήστρα"), "Clytemnestra");
+    assert_eq!(latinize("Σωκράτης"), "Socrates");
+    assert_eq!(latinize("Πλάτων"), "Plato");
+    assert_eq!(latinize("Οἰδίπους"), "Oedipus");
+    assert_eq!(latinize("Ἡρόδοτος"), "Herodotus");
+    assert_eq!(latinize("Φοῖβος Αἰσχύλος"), "Phoebus Aeschylus");
+    assert_eq!(latinize("Κύκλωψ"), "Cyclops");
+    assert_eq!(latinize("Μήδεια"), "Medea");
+    assert_eq!(latinize("Ἴλιον"), "Ilium");
+    assert_eq!(latinize("Τεῦκρος"), "Teucer");
+    assert_eq!(latinize("Πύρρος"), "Pyrrhus");
+    assert_eq!(latinize("Ἀγαμέμνων"), "Agamemnon");
+    assert_eq!(latinize("Σόλων"), "Solon");
+    assert_eq!(latinize("Ξενοφῶν"), "Xenophon");
+    assert_eq!(latinize("*pla/twn"), "Plato");
+}
+#[test]
+fn latinized_exceptions() {
+    let exceptions = HashMap::from([
+        ("Ὀδυσσεύς".to_string(), "Ulysses".to_string()),
+        ("ἀχιλλεύς".to_string(), "Achilleus".to_string()),
+    ]);
+    assert_eq!(latinize_with("Ὀδυσσεύς", &exceptions), "Ulysses");
+    assert_eq!(latinize_with("*)axilleu/s", &exceptions), "Achilleus");
+    assert_eq!(latinize_with("Περσεύς", &exceptions), "Perseus");
+}
@@ -0,0 +1,62 @@
+use crate::greek::{self, Letter, DIAERESIS, IOTA_SUBSCRIPT};
+
+/// Whether a vowel and the following one, which would read as a diphthong, are in hiatus:
+/// the breathing or the accent is then on the first vowel (`ἀΐδιος`, `Μάιος`).
+fn is_hiatus(previous: &Letter, letter: &Letter) -> bool {
+    let diphthong = matches!(
+        (previous.lower(), letter.lower()),
+        ('α' | 'ε' | 'ο' | 'υ', 'ι') | ('α' | 'ε' | 'η' | 'ο', 'υ')
+    );
+    diphthong
+        && !previous.has(IOTA_SUBSCRIPT)
+        && (previous.accent().is_some() || previous.breathing().is_some())
+}
+
+/// Renders a Unicode text in capitals, as [to_greek_uppercase].
+pub(crate) fn greek_uppercase(input: &str) -> String {
+    let letters = greek::letters(input);
+    let mut output = vec![];
+    for (i, letter) in letters.iter().enumerate() {
+        let base = letter.base.to_uppercase().next().unwrap_or(letter.base);
+        if !letter.is_greek() {
+            output.push(Letter {
+                base,
+                marks: letter.marks.clone(),
+            });
+            continue;
+        }
+        let hiatus = i > 0 && is_hiatus(&letters[i - 1], letter);
+        output.push(Letter {
+            base,
+            marks: match letter.has(DIAERESIS) || hiatus {
+                true => vec![DIAERESIS],
+                false => vec![],
+            },
+        });
+        if letter.has(IOTA_SUBSCRIPT) {
+            output.push(Letter {
+                base: 'Ι',
+                marks: vec![],
+            });
+        }
+    }
+    greek::render(&output)
+}
+
+/// Renders a Beta Code or Unicode text in Greek capitals.
+///
+/// Breathings, accents and quantity marks are dropped, as in all-caps typesetting.
+/// The diaeresis is kept, and added where the dropped breathing or accent marked a
+/// hiatus. The iota subscript (`|` in Beta Code) is written as a capital iota.
+///
+/// ```
+/// use betacode::uppercase::to_greek_uppercase;
+/// assert_eq!(to_greek_uppercase("μῆνιν ἄειδε θεὰ"), "ΜΗΝΙΝ ΑΕΙΔΕ ΘΕΑ");
+/// assert_eq!(to_greek_uppercase("tw=| a)i/+dios"), "ΤΩΙ ΑΪΔΙΟΣ");
+/// assert_eq!(to_greek_uppercase("Μάιος"), "ΜΑΪΟΣ");
+/// ```
+pub fn to_greek_uppercase<T: Into<String>>(input: T) -> String {
+    greek_uppercase(&greek::to_unicode(input))
+}
+#[cfg(test)]
+mod test;
@@ -0,0 +1,21 @@
+use super::*;
+
+#[test]
+fn accents_and_breathings() {
+    assert_eq!(to_greek_uppercase("ἄνθρωπος"), "ΑΝΘΡΩΠΟΣ");
+    assert_eq!(to_greek_uppercase("*)a/nqrwpos"), "ΑΝΘΡΩΠΟΣ");
+    assert_eq!(to_greek_uppercase("ὁ παῖς"), "Ο ΠΑΙΣ");
+}
+#[test]
+fn diaeresis() {
+    assert_eq!(to_greek_uppercase("Πηληϊάδεω"), "ΠΗΛΗΪΑΔΕΩ");
+    assert_eq!(to_greek_uppercase("ἀίδιος"), "ΑΪΔΙΟΣ");
+    assert_eq!(to_greek_uppercase("εἰ αὐτός"), "ΕΙ ΑΥΤΟΣ");
+    assert_eq!(to_greek_uppercase("ᾄδω"), "ΑΙΔΩ");
+}
+#[test]
+fn iota_subscript() {
+    assert_eq!(to_greek_uppercase("*(/a|dhs"), "ΑΙΔΗΣ");
+    assert_eq!(to_greek_uppercase("ᾍδης"), "ΑΙΔΗΣ");
+    assert_eq!(to_greek_uppercase("τῷ"), "ΤΩΙ");
+}
@@ -3,9 +3,9 @@ use std::fmt;
 
 /// Provides different classes of validation errors.
 /// - [ValidationError::InvalidChars]: Denotes cases in which the characters passed are not ASCII
-/// or not supported by this implementation of Betacode.
+///   or not supported by this implementation of Betacode.
 /// - [ValidationError::InvalidDiacriticOrder]: Denotes cases in which the sequence
-/// `BREATH/DIAIRESIS + ACCENT + SUB-IOTA` is not followed.
+///   `BREATH/DIAIRESIS + ACCENT + SUB-IOTA` is not followed.
 #[derive(Debug)]
 pub enum ValidationError {
     NotASCII(Vec<char>),
@@ -31,7 +31,9 @@ impl fmt::Display for ValidationError {
 
 fn diacritics_ordered<T: Into<String>>(input: T) -> Result<(), ValidationError> {
     let input: String = input.into();
-    let re = Regex::new(r"\|[()/\\+]+|[\\/][()+]|[qrtypsdfgklmnbcxz ][()\\/+|]+").unwrap();
+    let re = Regex::new(
+        r"\|[()/\\+]+|[\\/][()+]|[qtypsdfgklmnbcxz ][()\\/+|]+|r(?:[()][()\\/+|]|[\\/+|])[()\\/+|]*",
+    ).unwrap();
 
     let matches: Vec<regex::Match> = re.find_iter(&input).collect();
     match matches.len() {
@@ -52,7 +54,8 @@ fn standard_characteres<T: Into<String>>(input: T) -> Result<(), ValidationError
         'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
         'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'k', 'l',
         'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '*', '#', '|', ')',
-        '(', '/', '\\', '.', ';', ':', '1', '2', '3', ',', '\'', '+', '=', ' ', '\n',
+        '(', '/', '\\', '.', ';', ':', '1', '2', '3', '5', ',', '\'', '-', '+', '=', ' ', '\n',
+        '\r',
     ];
     match input.chars().all(|c| valid_chars.contains(&c)) {
         true => Ok(()),
@@ -22,3 +22,14 @@ fn invalid_betacode() {
     let result = validate(input);
     assert!(result.is_err());
 }
+#[test]
+fn crasis_and_rho() {
+    assert!(validate("ka)gw/ d' a)/ra").is_ok());
+    assert!(validate("r(h/twr a)/r)r(hn").is_ok());
+    assert!(validate("r/h").is_err());
+}
+#[test]
+fn crlf_line_ends() {
+    assert!(validate("mh=nin a)/ei-\r\nde qea\\\r\n").is_ok());
+    assert!(validate("mh=nin\ta)/eide").is_err());
+}
@@ -1,3 +1,4 @@
+use betacode::spellcheck::SpellChecker;
 use betacode::{converter, validator};
 use clap::Parser;
 use std::fs;
@@ -22,16 +23,43 @@ pub struct Args {
 
     #[clap(short, long, action)]
     pub inverse: bool,
+
+    /// Rejoins words hyphenated across line breaks
+    #[clap(long, action)]
+    pub dehyphenate: bool,
+
+    /// Marker left where a rejoined word was broken (implies --dehyphenate)
+    #[clap(long)]
+    pub break_marker: Option<char>,
+
+    /// Checks the spelling against a wordlist file instead of converting
+    #[clap(long)]
+    pub spellcheck: Option<String>,
 }
 
-fn convert_line(input: String) -> Result<String, validator::ValidationError> {
+impl Args {
+    fn convert_options(&self) -> converter::ConvertOptions {
+        converter::ConvertOptions {
+            dehyphenate: self.dehyphenate || self.break_marker.is_some(),
+            hyphen_marker: self.break_marker,
+            ..Default::default()
+        }
+    }
+}
+
+fn convert_line(
+    input: String,
+    options: &converter::ConvertOptions,
+) -> Result<String, validator::ValidationError> {
     match validator::validate(&input) {
-        Ok(()) => Ok(converter::convert(input)),
+        Ok(()) => Ok(converter::convert_with(input, options)),
         Err(e) => match e {
-            validator::ValidationError::InvalidDiacriticOrder(_) => Ok(converter::convert(input)),
+            validator::ValidationError::InvalidDiacriticOrder(_) => {
+                Ok(converter::convert_with(input, options))
+            }
             validator::ValidationError::MixedCaseNotation => {
                 log::warn!("Mixed case notation used, may contain errors.");
-                Ok(converter::convert(input))
+                Ok(converter::convert_with(input, options))
             }
             _ => Err(e),
         },
@@ -41,13 +69,33 @@ fn revert_line(input: String) -> String {
     converter::revert(input)
 }
 
-fn convert_line_strict(input: String) -> Result<String, validator::ValidationError> {
+fn convert_line_strict(
+    input: String,
+    options: &converter::ConvertOptions,
+) -> Result<String, validator::ValidationError> {
     match validator::validate(&input) {
-        Ok(()) => Ok(converter::convert(input)),
+        Ok(()) => Ok(converter::convert_with(input, options)),
         Err(e) => Err(e),
     }
 }
 
+fn spellcheck(input: &str, wordlist: &str) {
+    let checker = match SpellChecker::load(wordlist) {
+        Ok(checker) => checker,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1)
+        }
+    };
+    let errors = checker.check(input);
+    for error in &errors {
+        println!("{error}");
+    }
+    if !errors.is_empty() {
+        std::process::exit(1)
+    }
+}
+
 fn read_file(input: PathBuf) -> Result<String, std::io::Error> {
     let file: String = fs::read_to_string(input)?;
     Ok(file)
@@ -56,6 +104,7 @@ fn read_file(input: PathBuf) -> Result<String, std::io::Error> {
 fn main() -> Result<(), validator::ValidationError> {
     pretty_env_logger::init();
     let args = Args::parse();
+    let options = args.convert_options();
 
     let input_str: Option<String> = match args.file {
         true => match args.text {
@@ -74,6 +123,11 @@ fn main() -> Result<(), validator::ValidationError> {
         false => args.text,
     };
 
+    if let (Some(wordlist), Some(input_str)) = (&args.spellcheck, &input_str) {
+        spellcheck(input_str, wordlist);
+        return Ok(());
+    }
+
     match args.inverse {
         true => match input_str {
             None => {
@@ -104,7 +158,7 @@ fn main() -> Result<(), validator::ValidationError> {
                     eprintln! {"Empty string"};
                     std::process::exit(1)
                 }
-                Some(input_str) => match convert_line_strict(input_str) {
+                Some(input_str) => match convert_line_strict(input_str, &options) {
                     Ok(string) => {
                         println!("{string}");
                         Ok(())
@@ -138,7 +192,7 @@ fn main() -> Result<(), validator::ValidationError> {
                     eprintln! {"Empty string"};
                     std::process::exit(1)
                 }
-                Some(input_str) => match convert_line(input_str) {
+                Some(input_str) => match convert_line(input_str, &options) {
                     Ok(string) => match args.output {
                         None => {
                             println!("{string}");